@@ -0,0 +1,18 @@
+//! Runtime API definition for the kitties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_kitties::genetics::{Dna, KittyTraits};
+
+sp_api::decl_runtime_apis! {
+	pub trait KittiesApi<KittyIndex> where
+		KittyIndex: Codec,
+	{
+		/// Traits decoded from the DNA of `kitty_id`, if it exists and has hatched.
+		fn kitty_traits(kitty_id: KittyIndex) -> Option<KittyTraits>;
+
+		/// Traits a kitty with `dna` shows.
+		fn decode_dna(dna: Dna) -> KittyTraits;
+	}
+}
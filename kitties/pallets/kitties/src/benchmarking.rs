@@ -2,19 +2,247 @@
 
 use super::*;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get, Hooks};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+
+const SECRET: [u8; 32] = [7; 32];
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+fn commitment<T: Config>() -> T::Hash {
+	T::Hashing::hash(&SECRET)
+}
+
+/// Create `n` kitties for `owner`, returning the index of the first. Each egg hatches in the
+/// next block, after which the current one is restored.
+fn create_kitties<T: Config>(owner: &T::AccountId, n: u32) -> T::KittyIndex {
+	let first = Pallet::<T>::kitties_count();
+	let now = frame_system::Pallet::<T>::block_number();
+	for i in 0 .. n {
+		let origin = RawOrigin::Signed(owner.clone());
+		assert!(Pallet::<T>::create(origin.clone().into(), commitment::<T>()).is_ok());
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
+		assert!(Pallet::<T>::hatch(origin.into(), first + i.into(), SECRET).is_ok());
+		frame_system::Pallet::<T>::set_block_number(now);
+	}
+	first
+}
+
+/// A kitty bred by someone else, last of the `k + 1` kitties `seller` owns, so that selling it
+/// pays a royalty and scans all of them.
+fn kitty_for_sale<T: Config>(seller: &T::AccountId, k: u32) -> T::KittyIndex {
+	create_kitties::<T>(seller, k);
+	let breeder = funded_account::<T>("breeder", 0);
+	let kitty = create_kitties::<T>(&breeder, 1);
+	assert!(Pallet::<T>::transfer(RawOrigin::Signed(breeder).into(), kitty, seller.clone()).is_ok());
+	kitty
+}
+
+/// A price whose fee and royalty are enough to create the accounts they are paid to.
+fn sale_price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance() * 1_000u32.into()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	create {
+		let k in 0 .. T::MaxKittyOwned::get() - 1;
+		let caller = funded_caller::<T>();
+		create_kitties::<T>(&caller, k);
+		let egg = Pallet::<T>::kitties_count();
+	}: _(RawOrigin::Signed(caller.clone()), commitment::<T>())
+	verify {
+		assert_eq!(Pallet::<T>::kitties_owner(&caller).len() as u32, k + 1);
+		assert!(Eggs::<T>::contains_key(egg));
+	}
+
+	breed {
+		let k in 0 .. T::MaxKittyOwned::get() - 3;
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 2 + k);
+		let egg = Pallet::<T>::kitties_count();
+	}: _(RawOrigin::Signed(caller.clone()), kitty, kitty + 1u32.into(), commitment::<T>())
+	verify {
+		assert!(Eggs::<T>::contains_key(egg));
+	}
+
+	breed_with {
+		let k in 0 .. T::MaxKittyOwned::get() - 2;
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1 + k);
+		let sire_owner = funded_account::<T>("sire_owner", 0);
+		let sire = create_kitties::<T>(&sire_owner, 1);
+		let price = sale_price::<T>();
+		assert!(Pallet::<T>::offer_siring(RawOrigin::Signed(sire_owner).into(), sire, price).is_ok());
+		let egg = Pallet::<T>::kitties_count();
+	}: _(RawOrigin::Signed(caller.clone()), kitty, sire, commitment::<T>(), price)
+	verify {
+		assert!(Eggs::<T>::contains_key(egg));
+	}
+
+	hatch {
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 2);
+		let egg = Pallet::<T>::kitties_count();
+		assert!(Pallet::<T>::breed(RawOrigin::Signed(caller.clone()).into(), kitty, kitty + 1u32.into(), commitment::<T>()).is_ok());
+		let hatch_at = Eggs::<T>::get(egg).unwrap().hatch_at;
+		frame_system::Pallet::<T>::set_block_number(hatch_at + 1u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), egg, SECRET)
+	verify {
+		assert_last_event::<T>(Event::KittyBred(caller, kitty, kitty + 1u32.into(), egg).into())
+	}
+
+	transfer {
+		let k in 0 .. T::MaxKittyOwned::get() - 1;
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1 + k) + k.into();
+		let to = funded_account::<T>("to", 0);
+		create_kitties::<T>(&to, k);
+	}: _(RawOrigin::Signed(caller.clone()), kitty, to.clone())
+	verify {
+		assert_last_event::<T>(Event::KittyTransferred(caller, to, kitty).into())
+	}
+
+	set_price {
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1);
+		let price = Some(sale_price::<T>());
+	}: _(RawOrigin::Signed(caller.clone()), kitty, price)
+	verify {
+		assert_last_event::<T>(Event::KittyPriceSet(caller, kitty, price).into())
+	}
+
+	buy_kitty {
+		let k in 0 .. T::MaxKittyOwned::get() - 1;
+		let seller = funded_account::<T>("seller", 0);
+		let kitty = kitty_for_sale::<T>(&seller, k);
+		let price = sale_price::<T>();
+		assert!(Pallet::<T>::set_price(RawOrigin::Signed(seller).into(), kitty, Some(price)).is_ok());
+		let caller = funded_caller::<T>();
+		create_kitties::<T>(&caller, k);
+	}: _(RawOrigin::Signed(caller.clone()), kitty, price)
+	verify {
+		assert_eq!(Pallet::<T>::kitties(kitty).unwrap().owner, caller);
+	}
+
+	offer_siring {
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1);
+		let price = sale_price::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), kitty, price)
+	verify {
+		assert_last_event::<T>(Event::SiringOffered(caller, kitty, price).into())
+	}
+
+	cancel_siring {
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1);
+		assert!(Pallet::<T>::offer_siring(RawOrigin::Signed(caller.clone()).into(), kitty, sale_price::<T>()).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), kitty)
+	verify {
+		assert_last_event::<T>(Event::SiringCancelled(caller, kitty).into())
+	}
+
+	create_auction {
+		let caller = funded_caller::<T>();
+		let kitty = create_kitties::<T>(&caller, 1);
+		let price = sale_price::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), kitty, AuctionKind::English, price, price, 10u32.into())
+	verify {
+		assert!(Pallet::<T>::auctions(kitty).is_some());
+	}
+
+	// The worst case is buying from a Dutch auction, which sells the kitty right away.
+	bid {
+		let k = T::MaxKittyOwned::get() - 1;
+		let seller = funded_account::<T>("seller", 0);
+		let kitty = kitty_for_sale::<T>(&seller, k);
+		let price = sale_price::<T>();
+		assert!(Pallet::<T>::create_auction(RawOrigin::Signed(seller).into(), kitty, AuctionKind::Dutch, price, price, 10u32.into()).is_ok());
+		let caller = funded_caller::<T>();
+		create_kitties::<T>(&caller, k);
+	}: _(RawOrigin::Signed(caller.clone()), kitty, price)
+	verify {
+		assert_last_event::<T>(Event::AuctionSettled(kitty, Some((caller, price))).into())
+	}
+
+	// Settling an auction in `on_finalize` of the block it ends in.
+	settle_auction {
+		let k = T::MaxKittyOwned::get() - 1;
+		let seller = funded_account::<T>("seller", 0);
+		let kitty = kitty_for_sale::<T>(&seller, k);
+		let price = sale_price::<T>();
+		assert!(Pallet::<T>::create_auction(RawOrigin::Signed(seller).into(), kitty, AuctionKind::English, price, price, 10u32.into()).is_ok());
+		let bidder = funded_account::<T>("bidder", 0);
+		create_kitties::<T>(&bidder, k);
+		assert!(Pallet::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), kitty, price).is_ok());
+		let end = Pallet::<T>::auctions(kitty).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: {
+		Pallet::<T>::on_finalize(end);
+	}
+	verify {
+		assert_last_event::<T>(Event::AuctionSettled(kitty, Some((bidder, price))).into())
+	}
+
+	make_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty = create_kitties::<T>(&owner, 1);
+		let price = sale_price::<T>();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for i in 1 .. T::MaxOffersPerKitty::get() {
+			let buyer = funded_account::<T>("buyer", i);
+			assert!(Pallet::<T>::make_offer(RawOrigin::Signed(buyer).into(), kitty, price, expiry + i.into()).is_ok());
+		}
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), kitty, price, expiry)
+	verify {
+		assert_last_event::<T>(Event::OfferMade(caller, kitty, price, expiry).into())
+	}
+
+	// Accepting an offer cancels all the others.
+	accept_offer {
+		let k in 0 .. T::MaxKittyOwned::get() - 1;
+		let caller = funded_caller::<T>();
+		let kitty = kitty_for_sale::<T>(&caller, k);
+		let price = sale_price::<T>();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for i in 0 .. T::MaxOffersPerKitty::get() {
+			let buyer = funded_account::<T>("buyer", i);
+			assert!(Pallet::<T>::make_offer(RawOrigin::Signed(buyer).into(), kitty, price, expiry + i.into()).is_ok());
+		}
+		let buyer = funded_account::<T>("buyer", 0);
+		create_kitties::<T>(&buyer, k);
+	}: _(RawOrigin::Signed(caller), kitty, buyer.clone())
+	verify {
+		assert_eq!(Pallet::<T>::kitties(kitty).unwrap().owner, buyer);
+	}
+
+	withdraw_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let kitty = create_kitties::<T>(&owner, 1);
+		let caller = funded_caller::<T>();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(Pallet::<T>::make_offer(RawOrigin::Signed(caller.clone()).into(), kitty, sale_price::<T>(), expiry).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), kitty)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_last_event::<T>(Event::OfferCancelled(caller, kitty).into())
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
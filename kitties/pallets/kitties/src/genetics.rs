@@ -0,0 +1,97 @@
+//! Kitty genetics: how traits are read from DNA and passed on when breeding.
+//!
+//! Bytes `0..6` of the DNA hold three genes of two alleles each, for color, pattern and eye
+//! shape, the first allele of each pair being the one the kitty shows. Bytes `12..14` hold the
+//! generation as a little-endian `u16` and byte `14` the cooldown index. The other bytes are
+//! hidden genes, passed on bit by bit.
+//!
+//! An allele with its high bit set is dominant. A child gets one allele of every gene from each
+//! parent, picked at random, and shows the dominant one, or either of them at random when both
+//! or neither are dominant. Every inherited allele has a `MUTATION_CHANCE` in 256 of being
+//! replaced by a random one.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type Dna = [u8; 16];
+
+/// Chance in 256 that an inherited allele mutates.
+pub const MUTATION_CHANCE: u8 = 4;
+/// Highest cooldown index, reached at generation `2 * MAX_COOLDOWN`.
+pub const MAX_COOLDOWN: u8 = 13;
+
+const GENES: usize = 3;
+const GENERATION: usize = 12;
+const COOLDOWN: usize = 14;
+const DOMINANT: u8 = 0x80;
+
+/// Traits a kitty shows, as decoded from its DNA.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct KittyTraits {
+	pub color: u8,
+	pub pattern: u8,
+	pub eye_shape: u8,
+	/// 0 for created kitties, one more than the older parent's for bred ones.
+	pub generation: u16,
+	/// Cooldown index, half the generation up to `MAX_COOLDOWN`.
+	pub cooldown: u8,
+}
+
+/// Traits the kitty with `dna` shows.
+pub fn decode(dna: &Dna) -> KittyTraits {
+	KittyTraits {
+		color: dna[0] & !DOMINANT,
+		pattern: dna[2] & !DOMINANT,
+		eye_shape: dna[4] & !DOMINANT,
+		generation: generation(dna),
+		cooldown: dna[COOLDOWN],
+	}
+}
+
+/// DNA of a generation 0 kitty, made of `random` bytes.
+pub fn gen_zero(random: Dna) -> Dna {
+	let mut dna = random;
+	set_generation(&mut dna, 0);
+	dna
+}
+
+/// DNA of the child of `dna_1` and `dna_2`, with every choice made from `random`.
+pub fn breed(dna_1: &Dna, dna_2: &Dna, random: &[u8; 32]) -> Dna {
+	let mut child = [0u8; 16];
+	for gene in 0..GENES {
+		let at = 2 * gene;
+		let r = &random[4 * gene..4 * gene + 4];
+		let a = inherit(&dna_1[at..at + 2], r[0] & 1, r[1], r[3]);
+		let b = inherit(&dna_2[at..at + 2], r[0] >> 1 & 1, r[2], r[3].rotate_left(4));
+		let a_shown = match (a & DOMINANT != 0, b & DOMINANT != 0) {
+			(true, false) => true,
+			(false, true) => false,
+			_ => r[0] >> 2 & 1 == 0,
+		};
+		(child[at], child[at + 1]) = if a_shown { (a, b) } else { (b, a) };
+	}
+	for i in (2 * GENES..GENERATION).chain(COOLDOWN + 1..child.len()) {
+		let selector = random[4 * GENES + i];
+		child[i] = (selector & dna_1[i]) | (!selector & dna_2[i]);
+	}
+	set_generation(&mut child, generation(dna_1).max(generation(dna_2)).saturating_add(1));
+	child
+}
+
+fn inherit(alleles: &[u8], pick: u8, roll: u8, mutation: u8) -> u8 {
+	if roll < MUTATION_CHANCE {
+		mutation
+	} else {
+		alleles[pick as usize]
+	}
+}
+
+fn generation(dna: &Dna) -> u16 {
+	u16::from_le_bytes([dna[GENERATION], dna[GENERATION + 1]])
+}
+
+fn set_generation(dna: &mut Dna, generation: u16) {
+	dna[GENERATION..GENERATION + 2].copy_from_slice(&generation.to_le_bytes());
+	dna[COOLDOWN] = (generation / 2).min(MAX_COOLDOWN.into()) as u8;
+}
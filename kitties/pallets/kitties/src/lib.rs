@@ -5,6 +5,8 @@
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
 
+pub mod genetics;
+
 #[cfg(test)]
 mod mock;
 
@@ -14,28 +16,109 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use codec::{Decode, Encode};
 	use frame_support::{
 		dispatch::DispatchResult,
 		pallet_prelude::*,
-		traits::{Currency, Randomness, ReservableCurrency},
+		traits::{Currency, ExistenceRequirement, ReservableCurrency},
 		transactional, ensure,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_io::hashing::blake2_128;
-	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded};
+	pub use crate::weights::WeightInfo;
+	use crate::genetics::{self, KittyTraits};
+	use sp_io::hashing::{blake2_128, blake2_256};
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, Bounded, Hash, One, Saturating, Zero},
+		PerThing, Perbill, Permill,
+	};
 
-	type AccountOf<T> = <T as frame_system::Config>::AccountId;
-	type BalanceOf<T> =
+	pub type AccountOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, MaxEncodedLen)]
 	pub struct Kitty<T: Config> {
 		pub dna: [u8; 16],
 		pub price: Option<BalanceOf<T>>,
 		pub owner: AccountOf<T>,
+		/// Kitties this one was bred from, `None` for kitties created with `create`.
+		pub parents: Option<(T::KittyIndex, T::KittyIndex)>,
+		/// Generation encoded in the DNA, see [`crate::genetics`].
+		pub generation: u16,
+		/// Block the kitty's egg was due in.
+		pub birth_block: T::BlockNumber,
+		/// Block from which the kitty may breed again.
+		pub next_breed_at: T::BlockNumber,
+		/// Account that created or bred the kitty, paid a royalty whenever it is sold.
+		pub breeder: AccountOf<T>,
+	}
+
+	/// A kitty laid by `create` or bred with `breed` whose DNA is not known yet. Its owner
+	/// commits to a secret when laying it and reveals it with `hatch` after block `hatch_at`.
+	/// The DNA is drawn from the secret and the hash of that block, so that an owner, who does
+	/// not know the hash when committing, and a block author, who does not know the secret,
+	/// cannot pick rare DNA on their own.
+	///
+	/// An owner who also authors block `hatch_at` knows both, and can try out what goes into
+	/// the block until its hash gives rare DNA. With Aura, authors are known ahead of their
+	/// slots, and eggs laid by `create` are due in the very block that lays them. Drawing the
+	/// DNA from a VRF output, such as BABE's, instead of a block hash would rule this out.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, MaxEncodedLen)]
+	pub struct Egg<T: Config> {
+		pub owner: AccountOf<T>,
+		/// Kitties the egg was bred from, `None` for eggs laid by `create`.
+		pub parents: Option<(T::KittyIndex, T::KittyIndex)>,
+		/// DNA of the parents when the egg was laid, zeroed for eggs laid by `create`.
+		pub parents_dna: [[u8; 16]; 2],
+		/// Block the egg was laid in.
+		pub laid_at: T::BlockNumber,
+		/// Block whose hash the DNA is drawn from. The egg may hatch in the `RevealPeriod`
+		/// blocks after it.
+		pub hatch_at: T::BlockNumber,
+		/// Hash of the secret revealed to hatch the egg.
+		pub commitment: T::Hash,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub enum AuctionKind {
+		/// Bids go up from the start price, and the highest one wins at the end if it reaches
+		/// the reserve price.
+		English,
+		/// The price goes down from the start price to the end price, and the first bid at the
+		/// current price wins.
+		Dutch,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, MaxEncodedLen)]
+	pub struct Auction<T: Config> {
+		pub seller: AccountOf<T>,
+		pub kind: AuctionKind,
+		pub start_price: BalanceOf<T>,
+		/// Reserve price of English auctions, price reached at the end of Dutch auctions.
+		pub end_price: BalanceOf<T>,
+		/// Block the auction was created in.
+		pub start: T::BlockNumber,
+		/// Last block bids are taken in.
+		pub end: T::BlockNumber,
+		/// Highest bid of English auctions, whose funds are reserved.
+		pub best_bid: Option<(AccountOf<T>, BalanceOf<T>)>,
+	}
+
+	/// An offer to buy a kitty, whose amount is reserved from the buyer.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, MaxEncodedLen)]
+	pub struct Offer<T: Config> {
+		pub buyer: AccountOf<T>,
+		pub amount: BalanceOf<T>,
+		/// Block at the start of which the offer expires.
+		pub expiry: T::BlockNumber,
 	}
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -43,7 +126,6 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + TypeInfo {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 		// Define KittyIndex in Runtime.
 		type KittyIndex: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Default + Copy + Bounded;
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
@@ -54,10 +136,57 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxKittyOwned: Get<u32>;
+
+		// Number of blocks a bred egg incubates before it can hatch. Eggs laid by `create` may
+		// hatch from the next block.
+		#[pallet::constant]
+		type EggIncubation: Get<Self::BlockNumber>;
+
+		// Number of blocks after an egg is due in which its owner may hatch it. Eggs not
+		// hatched by then are discarded and their stake slashed. Must be below
+		// `BlockHashCount`, for the hash of the block the egg was due in to still be known.
+		#[pallet::constant]
+		type RevealPeriod: Get<Self::BlockNumber>;
+
+		// Maximum number of eggs that may be discarded in the same block.
+		#[pallet::constant]
+		type MaxEggsPerBlock: Get<u32>;
+
+		// Number of blocks a kitty rests after breeding at cooldown index 0. Every cooldown
+		// index, which grows with the generation, adds as much again.
+		#[pallet::constant]
+		type BreedCooldown: Get<Self::BlockNumber>;
+
+		// Maximum number of auctions that may end in the same block, all settled when it is
+		// finalized.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
+		// Part of every sale price paid to `TreasuryAccount`.
+		#[pallet::constant]
+		type MarketplaceFee: Get<Permill>;
+
+		// Part of every sale price paid to the breeder of the kitty sold.
+		#[pallet::constant]
+		type Royalty: Get<Permill>;
+
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		// Maximum number of offers standing for the same kitty.
+		#[pallet::constant]
+		type MaxOffersPerKitty: Get<u32>;
+
+		// Maximum number of offers expiring in the same block.
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
+
+		// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Storage for tracking all the kitties
@@ -82,12 +211,104 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Eggs laid by `create` and `breed`, each waiting to hatch into the kitty of the same index.
+	#[pallet::storage]
+	#[pallet::getter(fn eggs)]
+	pub type Eggs<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, Egg<T>>;
+
+	/// Eggs discarded at the start of the given block unless hatched before.
+	#[pallet::storage]
+	pub type EggDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::KittyIndex, T::MaxEggsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Kitties up for auction. They cannot be transferred, sold or bred until it is settled.
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, Auction<T>>;
+
+	/// Auctions to settle when the given block is finalized.
+	#[pallet::storage]
+	pub type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::KittyIndex, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Offers to buy a kitty, standing until accepted, withdrawn or expired, or until the kitty
+	/// changes hands.
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::KittyIndex,
+		BoundedVec<Offer<T>, T::MaxOffersPerKitty>,
+		ValueQuery,
+	>;
+
+	/// Offers expiring at the start of the given block, by kitty and buyer.
+	#[pallet::storage]
+	pub type OfferExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(T::KittyIndex, T::AccountId), T::MaxOffersPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Fee asked by the owner of a kitty for breeding with it once through `breed_with`.
+	#[pallet::storage]
+	#[pallet::getter(fn siring_offers)]
+	pub type SiringOffers<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyIndex, BalanceOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		KittyCreated(T::AccountId, T::KittyIndex),
 		KittyTransferred(T::AccountId, T::AccountId, T::KittyIndex),
 		KittyPriceSet(T::AccountId, T::KittyIndex, Option<BalanceOf<T>>),
+		/// An egg was laid and may hatch from the given block. [owner, kitty_id, hatch_at]
+		EggLaid(T::AccountId, T::KittyIndex, T::BlockNumber),
+		/// An egg hatched into a kitty bred from two others. [owner, parent1, parent2, child]
+		KittyBred(T::AccountId, T::KittyIndex, T::KittyIndex, T::KittyIndex),
+		/// An egg was not hatched in time, and its stake was slashed. [owner, kitty_id]
+		EggDiscarded(T::AccountId, T::KittyIndex),
+		/// A kitty was offered for siring. [owner, kitty_id, price]
+		SiringOffered(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// A siring offer was withdrawn. [owner, kitty_id]
+		SiringCancelled(T::AccountId, T::KittyIndex),
+		/// A kitty was bred with another owner's kitty for its siring fee.
+		/// [breeder, sire_owner, sire_id, price]
+		SiringBought(T::AccountId, T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// A kitty was put up for auction until the given block. [seller, kitty_id, kind, end]
+		AuctionCreated(T::AccountId, T::KittyIndex, AuctionKind, T::BlockNumber),
+		/// A bid was placed in an English auction. [bidder, kitty_id, amount]
+		AuctionBid(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		/// An auction ended, with the buyer and price if the kitty was sold.
+		/// [kitty_id, sale]
+		AuctionSettled(T::KittyIndex, Option<(T::AccountId, BalanceOf<T>)>),
+		/// A kitty was sold, and the price split between the seller, the treasury and the
+		/// breeder. [seller, buyer, kitty_id, price, fee, royalty]
+		KittySold(
+			T::AccountId,
+			T::AccountId,
+			T::KittyIndex,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// An offer was made for a kitty. [buyer, kitty_id, amount, expiry]
+		OfferMade(T::AccountId, T::KittyIndex, BalanceOf<T>, T::BlockNumber),
+		/// An offer was withdrawn or expired, or the kitty changed hands, and its amount was
+		/// returned. [buyer, kitty_id]
+		OfferCancelled(T::AccountId, T::KittyIndex),
 	}
 
 	#[pallet::error]
@@ -102,6 +323,83 @@ pub mod pallet {
 		NotEnoughBalanceForStaking,
 		NotEnoughBalanceForBuying,
 		TransferToSelf,
+		EggNotExist,
+		/// The egg is still incubating.
+		EggNotReady,
+		/// Too many eggs already have the deadline this one would.
+		TooManyEggs,
+		/// The secret does not match the commitment the egg was laid with.
+		WrongSecret,
+		/// One of the kitties is still resting from breeding.
+		KittyOnCooldown,
+		/// A kitty cannot be bred with one of its parents.
+		BreedWithParent,
+		/// A kitty cannot be bred with a kitty sharing one of its parents.
+		BreedWithSibling,
+		/// The kitty is not offered for siring.
+		KittyNotForSiring,
+		/// The kitty is up for auction.
+		KittyInAuction,
+		/// The kitty is not up for auction.
+		AuctionNotExist,
+		/// Auctions must last at least a block, and Dutch ones must not go up in price.
+		InvalidAuction,
+		/// The auction has already ended.
+		AuctionEnded,
+		/// `MaxAuctionsPerBlock` auctions already end in the block this one would.
+		TooManyAuctionsEnding,
+		/// The bid is below the start price, the highest bid or the current price.
+		BidTooLow,
+		/// The buyer already has an offer standing for the kitty.
+		OfferAlreadyExist,
+		OfferNotExist,
+		/// The offer would expire before the next block.
+		OfferExpired,
+		/// Too many offers stand for the kitty, or expire in the same block.
+		TooManyOffers,
+		/// The kitty, or breeding with it, costs more than the buyer is willing to pay.
+		PriceAboveMax,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let overdue = EggDeadlines::<T>::take(n);
+			let count = overdue.len() as Weight;
+			for kitty_id in overdue {
+				Self::discard_egg(kitty_id);
+			}
+			let expired = OfferExpiries::<T>::take(n);
+			let offers = expired.len() as Weight;
+			for (kitty_id, buyer) in expired {
+				if Self::remove_offer(kitty_id, &buyer).is_ok() {
+					Self::deposit_event(Event::OfferCancelled(buyer, kitty_id));
+				}
+			}
+			// Auctions ending in this block are settled in `on_finalize`.
+			let auctions = AuctionsEnding::<T>::decode_len(n).unwrap_or(0) as Weight;
+			T::DbWeight::get()
+				.reads_writes(3 + 3 * count + 2 * offers, 2 + 3 * count + 2 * offers)
+				.saturating_add(T::WeightInfo::settle_auction().saturating_mul(auctions))
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			for kitty_id in AuctionsEnding::<T>::take(n) {
+				let _ = Self::do_settle_auction(kitty_id);
+			}
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MarketplaceFee::get().deconstruct() + T::Royalty::get().deconstruct() <=
+					Permill::one().deconstruct(),
+				"the marketplace fee and royalty must be taken out of the sale price"
+			);
+			assert!(
+				T::RevealPeriod::get() < T::BlockHashCount::get(),
+				"eggs must hatch while the hash of the block they were due in is known"
+			);
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -109,67 +407,177 @@ pub mod pallet {
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::weight(1_000)]
+		/// Lay an egg of a kitty, taking the stake and a kitty index right away. `commitment` is
+		/// the hash of a secret the caller reveals with `hatch` from the next block on.
+		#[pallet::weight(T::WeightInfo::create(T::MaxKittyOwned::get()))]
 		#[transactional]
-		pub fn create(origin: OriginFor<T>) -> DispatchResult {
+		pub fn create(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			//get kitty dna
-			let dna = Self::random_value(&who);
-			//new kitty with stake
-			Self::new_kitty_with_stake(who, dna)?;
-			Ok(())
+			let owned = Self::kitties_owned(&who);
+			let now = <frame_system::Pallet<T>>::block_number();
+			Self::lay_egg(who, None, [[0; 16]; 2], now, commitment)?;
+			Ok(Some(T::WeightInfo::create(owned)).into())
 		}
 
-		#[pallet::weight(1_000)]
+		/// Breed two kitties of the caller into an egg, taking the stake and a kitty index for
+		/// the child right away. The egg is due after `EggIncubation` blocks, and hatches when
+		/// the caller reveals the secret hashed into `commitment` with `hatch`.
+		///
+		/// Both parents then rest for a cooldown that grows with their generation. Kitties
+		/// cannot be bred with their parents or siblings.
+		#[pallet::weight(T::WeightInfo::breed(T::MaxKittyOwned::get()))]
 		#[transactional]
 		pub fn breed(
 			origin: OriginFor<T>,
 			kitty_id_1: T::KittyIndex,
 			kitty_id_2: T::KittyIndex,
-		) -> DispatchResult {
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			ensure!(kitty_id_1 != kitty_id_2, Error::<T>::SameParentIndex);
 			let kitty1 = Self::kitties(kitty_id_1).ok_or(Error::<T>::InvalidKittyIndex)?;
 			let kitty2 = Self::kitties(kitty_id_2).ok_or(Error::<T>::InvalidKittyIndex)?;
+			ensure!(kitty1.owner == who && kitty2.owner == who, Error::<T>::NotOwner);
 
-			let dna_1 = kitty1.dna;
-			let dna_2 = kitty2.dna;
+			let others = Self::kitties_owned(&who).saturating_sub(2);
+			Self::breed_egg(who, (kitty_id_1, kitty1), (kitty_id_2, kitty2), commitment)?;
+			Ok(Some(T::WeightInfo::breed(others)).into())
+		}
 
-			let selector = Self::random_value(&who);
-			let mut new_dna = [0u8; 16];
+		/// Breed a kitty of the caller with `sire_id`, a kitty of another owner offered for
+		/// siring, paying its owner the fee asked provided it is not above `max_price`, so that
+		/// the owner cannot raise it in the meantime. The offer is used up, and the egg belongs
+		/// to the caller as with `breed`.
+		#[pallet::weight(T::WeightInfo::breed_with(T::MaxKittyOwned::get()))]
+		#[transactional]
+		pub fn breed_with(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			sire_id: T::KittyIndex,
+			commitment: T::Hash,
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-			for i in 0..dna_1.len() {
-				new_dna[i] = (selector[i] & dna_1[i]) | (!selector[i] & dna_2[i]);
-			}
-			//new kitty with stake
-			Self::new_kitty_with_stake(who, new_dna)?;
+			ensure!(kitty_id != sire_id, Error::<T>::SameParentIndex);
+			let kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+			let sire = Self::kitties(sire_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+			ensure!(kitty.owner == who, Error::<T>::NotOwner);
+			ensure!(sire.owner != who, Error::<T>::BuyerIsOwner);
+			let price = SiringOffers::<T>::take(sire_id).ok_or(Error::<T>::KittyNotForSiring)?;
+			ensure!(price <= max_price, Error::<T>::PriceAboveMax);
+
+			T::Currency::transfer(&who, &sire.owner, price, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::SiringBought(
+				who.clone(),
+				sire.owner.clone(),
+				sire_id,
+				price,
+			));
+			let others = Self::kitties_owned(&who).saturating_sub(1);
+			Self::breed_egg(who, (kitty_id, kitty), (sire_id, sire), commitment)?;
+			Ok(Some(T::WeightInfo::breed_with(others)).into())
+		}
+
+		/// Offer a kitty for siring: anyone else may breed with it once through `breed_with`
+		/// for `price`. Replaces any previous offer, and lapses when the kitty changes hands.
+		#[pallet::weight(T::WeightInfo::offer_siring())]
+		pub fn offer_siring(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_kitty_owner(kitty_id, &who)?, Error::<T>::NotOwner);
+			SiringOffers::<T>::insert(kitty_id, price);
+			Self::deposit_event(Event::SiringOffered(who, kitty_id, price));
+			Ok(())
+		}
 
+		/// Withdraw the siring offer of a kitty.
+		#[pallet::weight(T::WeightInfo::cancel_siring())]
+		pub fn cancel_siring(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_kitty_owner(kitty_id, &who)?, Error::<T>::NotOwner);
+			SiringOffers::<T>::take(kitty_id).ok_or(Error::<T>::KittyNotForSiring)?;
+			Self::deposit_event(Event::SiringCancelled(who, kitty_id));
 			Ok(())
 		}
 
-		#[pallet::weight(1_000)]
+		/// Hatch an egg by revealing the secret its commitment is the hash of, in one of the
+		/// `RevealPeriod` blocks after the one it was due in. Eggs laid by `create` hatch into
+		/// generation 0 kitties, and bred ones get their DNA from their parents as described in
+		/// `genetics`.
+		#[pallet::weight(T::WeightInfo::hatch())]
+		pub fn hatch(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			secret: [u8; 32],
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let egg = Self::eggs(kitty_id).ok_or(Error::<T>::EggNotExist)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now > egg.hatch_at, Error::<T>::EggNotReady);
+			ensure!(T::Hashing::hash(&secret) == egg.commitment, Error::<T>::WrongSecret);
+
+			let seed = <frame_system::Pallet<T>>::block_hash(egg.hatch_at);
+			let random = (secret, seed, kitty_id).using_encoded(blake2_256);
+			let dna = match egg.parents {
+				Some(_) => {
+					let [dna_1, dna_2] = egg.parents_dna;
+					genetics::breed(&dna_1, &dna_2, &random)
+				},
+				None => genetics::gen_zero(random.using_encoded(blake2_128)),
+			};
+
+			Eggs::<T>::remove(kitty_id);
+			EggDeadlines::<T>::mutate(Self::egg_deadline(egg.hatch_at), |overdue| {
+				overdue.retain(|id| *id != kitty_id)
+			});
+			Kitties::<T>::insert(
+				kitty_id,
+				Some(Kitty {
+					dna,
+					price: None,
+					owner: egg.owner.clone(),
+					parents: egg.parents,
+					generation: genetics::decode(&dna).generation,
+					birth_block: egg.hatch_at,
+					next_breed_at: egg.hatch_at,
+					breeder: egg.owner.clone(),
+				}),
+			);
+			Self::deposit_event(match egg.parents {
+				Some((parent1, parent2)) =>
+					Event::KittyBred(egg.owner, parent1, parent2, kitty_id),
+				None => Event::KittyCreated(egg.owner, kitty_id),
+			});
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer(T::MaxKittyOwned::get()))]
 		#[transactional]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			kitty_id: T::KittyIndex,
 			to: T::AccountId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			ensure!(who != to, <Error<T>>::TransferToSelf);
 			let kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
 			ensure!(Self::is_kitty_owner(kitty_id, &who)?, Error::<T>::NotOwner);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
 			let stake_amount = T::StakeForEachKitty::get();
 			T::Currency::reserve(&to, stake_amount)
 				.map_err(|_| Error::<T>::NotEnoughBalanceForStaking)?;
 			T::Currency::unreserve(&kitty.owner, stake_amount);
+			let owned = Self::kitties_moved_between(&kitty.owner, &to);
 			Self::transfer_kitty_to(kitty_id, to)?;
-			Ok(())
+			Ok(Some(T::WeightInfo::transfer(owned)).into())
 		}
 
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::set_price())]
 		pub fn set_price(
 			origin: OriginFor<T>,
 			kitty_id: T::KittyIndex,
@@ -184,69 +592,433 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(1_000)]
+		/// Buy a kitty at the price its owner set, provided it is not above `max_price`, so that
+		/// the owner cannot raise it in the meantime.
+		#[pallet::weight(T::WeightInfo::buy_kitty(T::MaxKittyOwned::get()))]
 		#[transactional]
-		pub fn buy_kitty(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+		pub fn buy_kitty(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let buyer = ensure_signed(origin)?;
 			let kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
 			ensure!(kitty.owner != buyer, <Error<T>>::TransferToSelf);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
 
 			if let Some(ask_price) = kitty.price {
+				ensure!(ask_price <= max_price, Error::<T>::PriceAboveMax);
 				let amount = ask_price;
 				let stake_amount = T::StakeForEachKitty::get();
 				let buyer_balance = T::Currency::free_balance(&buyer);
 				ensure!(
-					buyer_balance > (amount + stake_amount),
+					buyer_balance > amount.saturating_add(stake_amount),
 					Error::<T>::NotEnoughBalanceForBuying
 				);
-				T::Currency::reserve(&buyer, stake_amount)
-					.map_err(|_| Error::<T>::NotEnoughBalanceForStaking)?;
-				T::Currency::unreserve(&kitty.owner, stake_amount);
-				T::Currency::transfer(
-					&buyer,
-					&kitty.owner,
-					amount,
-					frame_support::traits::ExistenceRequirement::KeepAlive,
-				)?;
-				Self::transfer_kitty_to(kitty_id, buyer)?;
+				let owned = Self::kitties_moved_between(&kitty.owner, &buyer);
+				Self::sell_kitty(kitty_id, &kitty.owner, buyer, amount)?;
+				Ok(Some(T::WeightInfo::buy_kitty(owned)).into())
 			} else {
-				Err(<Error<T>>::KittyNotForSale)?;
+				Err(Error::<T>::KittyNotForSale.into())
+			}
+		}
+
+		/// Put a kitty up for auction, taking bids until `duration` blocks from now. English
+		/// auctions start bidding at `start_price` and sell if the highest bid reaches
+		/// `end_price`. Dutch auctions sell to the first bid at a price going down from
+		/// `start_price` to `end_price`.
+		///
+		/// The kitty cannot be transferred, sold or bred while up for auction.
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			kind: AuctionKind,
+			start_price: BalanceOf<T>,
+			end_price: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_kitty_owner(kitty_id, &who)?, Error::<T>::NotOwner);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!duration.is_zero(), Error::<T>::InvalidAuction);
+			ensure!(
+				kind == AuctionKind::English || start_price >= end_price,
+				Error::<T>::InvalidAuction
+			);
+
+			let start = <frame_system::Pallet<T>>::block_number();
+			let end = start.saturating_add(duration);
+			AuctionsEnding::<T>::try_mutate(end, |ending| ending.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyAuctionsEnding)?;
+			Auctions::<T>::insert(
+				kitty_id,
+				Auction {
+					seller: who.clone(),
+					kind,
+					start_price,
+					end_price,
+					start,
+					end,
+					best_bid: None,
+				},
+			);
+			Self::deposit_event(Event::AuctionCreated(who, kitty_id, kind, end));
+			Ok(())
+		}
+
+		/// Bid `amount` for a kitty up for auction. In English auctions the amount is reserved
+		/// until the bid is outbid or the auction settled. In Dutch auctions the kitty is bought
+		/// right away at the current price, which `amount` must cover.
+		#[pallet::weight(T::WeightInfo::bid())]
+		#[transactional]
+		pub fn bid(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut auction = Self::auctions(kitty_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction.seller != who, Error::<T>::BuyerIsOwner);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now <= auction.end, Error::<T>::AuctionEnded);
+
+			match auction.kind {
+				AuctionKind::English => {
+					ensure!(
+						amount >= auction.start_price &&
+							auction.best_bid.as_ref().map_or(true, |(_, best)| amount > *best),
+						Error::<T>::BidTooLow
+					);
+					T::Currency::reserve(&who, amount)
+						.map_err(|_| Error::<T>::NotEnoughBalanceForBuying)?;
+					if let Some((bidder, best)) = auction.best_bid.replace((who.clone(), amount)) {
+						T::Currency::unreserve(&bidder, best);
+					}
+					Auctions::<T>::insert(kitty_id, auction);
+					Self::deposit_event(Event::AuctionBid(who, kitty_id, amount));
+				},
+				AuctionKind::Dutch => {
+					let price = Self::dutch_price(&auction, now);
+					ensure!(amount >= price, Error::<T>::BidTooLow);
+					Auctions::<T>::remove(kitty_id);
+					AuctionsEnding::<T>::mutate(auction.end, |ending| {
+						ending.retain(|id| *id != kitty_id)
+					});
+					Self::sell_kitty(kitty_id, &auction.seller, who.clone(), price)?;
+					Self::deposit_event(Event::AuctionSettled(kitty_id, Some((who, price))));
+				},
 			}
 			Ok(())
 		}
+
+		/// Offer to buy a kitty for `amount`, whether it is for sale or not. The amount is
+		/// reserved until the owner accepts the offer, the buyer withdraws it, the kitty changes
+		/// hands or the offer expires at the start of block `expiry`.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+			ensure!(kitty.owner != who, Error::<T>::BuyerIsOwner);
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(expiry > now, Error::<T>::OfferExpired);
+			ensure!(
+				!Self::offers(kitty_id).iter().any(|offer| offer.buyer == who),
+				Error::<T>::OfferAlreadyExist
+			);
+
+			T::Currency::reserve(&who, amount)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForBuying)?;
+			Offers::<T>::try_mutate(kitty_id, |offers| {
+				offers.try_push(Offer { buyer: who.clone(), amount, expiry })
+			})
+			.map_err(|_| Error::<T>::TooManyOffers)?;
+			OfferExpiries::<T>::try_mutate(expiry, |expiring| {
+				expiring.try_push((kitty_id, who.clone()))
+			})
+			.map_err(|_| Error::<T>::TooManyOffers)?;
+			Self::deposit_event(Event::OfferMade(who, kitty_id, amount, expiry));
+			Ok(())
+		}
+
+		/// Sell a kitty to `buyer` at the amount they offered. Other offers for it are
+		/// cancelled.
+		#[pallet::weight(T::WeightInfo::accept_offer(T::MaxKittyOwned::get()))]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyIndex,
+			buyer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_kitty_owner(kitty_id, &who)?, Error::<T>::NotOwner);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+
+			let offer = Self::remove_offer(kitty_id, &buyer)?;
+			Self::remove_offer_expiry(kitty_id, &offer);
+			let owned = Self::kitties_moved_between(&who, &buyer);
+			Self::sell_kitty(kitty_id, &who, buyer, offer.amount)?;
+			Ok(Some(T::WeightInfo::accept_offer(owned)).into())
+		}
+
+		/// Withdraw the offer of the caller for a kitty, returning its amount.
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Self::remove_offer(kitty_id, &who)?;
+			Self::remove_offer_expiry(kitty_id, &offer);
+			Self::deposit_event(Event::OfferCancelled(who, kitty_id));
+			Ok(())
+		}
 	}
 
 	// Helper functions.
 	impl<T: Config> Pallet<T> {
-		fn random_value(sender: &T::AccountId) -> [u8; 16] {
-			let payload = (
-				T::Randomness::random_seed(),
-				&sender,
-				<frame_system::Pallet<T>>::extrinsic_index(),
-			);
-			payload.using_encoded(blake2_128)
-		}
-		// Helper function for optimizing the codes from create() and transfer().
-		fn new_kitty_with_stake(owner: T::AccountId, dna: [u8; 16]) -> Result<(), Error<T>> {
-			let kitty_id =  Self::kitties_count();
+		// Take the next kitty index for `owner`, counting it towards `MaxKittyOwned` and
+		// reserving its stake.
+		fn reserve_kitty(owner: &T::AccountId) -> Result<T::KittyIndex, Error<T>> {
+			let kitty_id = Self::kitties_count();
+			ensure!(kitty_id != T::KittyIndex::max_value(), Error::<T>::KittiesCountOverflow);
 
-			KittiesOwner::<T>::try_mutate(&owner, |vec| vec.try_push(kitty_id))
+			KittiesOwner::<T>::try_mutate(owner, |vec| vec.try_push(kitty_id))
 				.map_err(|_| <Error<T>>::ExceedKittyOwned)?;
 
 			let stake = T::StakeForEachKitty::get();
 
-			T::Currency::reserve(&owner, stake)
+			T::Currency::reserve(owner, stake)
 				.map_err(|_| Error::<T>::NotEnoughBalanceForStaking)?;
 
-			Kitties::<T>::insert(kitty_id, Some(Kitty { dna, price: None, owner: owner.clone() }));
+			KittiesCount::<T>::put(kitty_id + 1u32.into());
+			Ok(kitty_id)
+		}
 
+		// Lay an egg of `owner` due in block `hatch_at`, taking its kitty index and stake.
+		fn lay_egg(
+			owner: T::AccountId,
+			parents: Option<(T::KittyIndex, T::KittyIndex)>,
+			parents_dna: [[u8; 16]; 2],
+			hatch_at: T::BlockNumber,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let kitty_id = Self::reserve_kitty(&owner)?;
+			let laid_at = <frame_system::Pallet<T>>::block_number();
+			EggDeadlines::<T>::try_mutate(Self::egg_deadline(hatch_at), |overdue| {
+				overdue.try_push(kitty_id)
+			})
+			.map_err(|_| Error::<T>::TooManyEggs)?;
+			Eggs::<T>::insert(
+				kitty_id,
+				Egg { owner: owner.clone(), parents, parents_dna, laid_at, hatch_at, commitment },
+			);
+			Self::deposit_event(Event::EggLaid(owner, kitty_id, hatch_at));
+			Ok(())
+		}
 
+		/// Block at the start of which an egg due in `hatch_at` is discarded if not hatched.
+		pub fn egg_deadline(hatch_at: T::BlockNumber) -> T::BlockNumber {
+			hatch_at.saturating_add(T::RevealPeriod::get()).saturating_add(One::one())
+		}
 
-			KittiesCount::<T>::put(kitty_id + 1u32.into());
-			Self::deposit_event(Event::KittyCreated(owner.clone(), kitty_id));
+		// Discard an egg that was not hatched in time, freeing its kitty index from the owner's
+		// and slashing its stake, so that owners cannot keep only the eggs they like.
+		fn discard_egg(kitty_id: T::KittyIndex) {
+			if let Some(egg) = Eggs::<T>::take(kitty_id) {
+				KittiesOwner::<T>::mutate(&egg.owner, |owned| owned.retain(|id| *id != kitty_id));
+				let _ = T::Currency::slash_reserved(&egg.owner, T::StakeForEachKitty::get());
+				Self::deposit_event(Event::EggDiscarded(egg.owner, kitty_id));
+			}
+		}
+
+		// Lay an egg of `owner` bred from two kitties, once they are checked to be able to mate,
+		// and start their cooldowns.
+		fn breed_egg(
+			owner: T::AccountId,
+			(id_1, kitty1): (T::KittyIndex, Kitty<T>),
+			(id_2, kitty2): (T::KittyIndex, Kitty<T>),
+			commitment: T::Hash,
+		) -> DispatchResult {
+			ensure!(
+				!Auctions::<T>::contains_key(id_1) && !Auctions::<T>::contains_key(id_2),
+				Error::<T>::KittyInAuction
+			);
+			Self::ensure_can_mate((id_1, &kitty1), (id_2, &kitty2))?;
+
+			let laid_at = <frame_system::Pallet<T>>::block_number();
+			let parents_dna = [kitty1.dna, kitty2.dna];
+			Self::start_cooldown(id_1, kitty1, laid_at);
+			Self::start_cooldown(id_2, kitty2, laid_at);
+			let hatch_at = laid_at + T::EggIncubation::get();
+			Self::lay_egg(owner, Some((id_1, id_2)), parents_dna, hatch_at, commitment)
+		}
+
+		// Hand `kitty_id` over from `seller` to `buyer` for `amount`, moving the stake along.
+		// `MarketplaceFee` of the amount goes to the treasury and `Royalty` to the breeder,
+		// unless the breeder is the seller, and the seller gets the rest. A fee or royalty too
+		// small to create the account it is paid to goes to the seller too.
+		#[transactional]
+		fn sell_kitty(
+			kitty_id: T::KittyIndex,
+			seller: &T::AccountId,
+			buyer: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let kitty = Self::kitties(kitty_id).ok_or(Error::<T>::InvalidKittyIndex)?;
+			let stake_amount = T::StakeForEachKitty::get();
+			T::Currency::reserve(&buyer, stake_amount)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForStaking)?;
+			T::Currency::unreserve(seller, stake_amount);
+
+			let treasury = T::TreasuryAccount::get();
+			let fee = Self::payable(&treasury, T::MarketplaceFee::get() * amount);
+			let royalty = if kitty.breeder == *seller {
+				Zero::zero()
+			} else {
+				let royalty = (T::Royalty::get() * amount).min(amount.saturating_sub(fee));
+				Self::payable(&kitty.breeder, royalty)
+			};
+			T::Currency::transfer(&buyer, &treasury, fee, ExistenceRequirement::KeepAlive)?;
+			T::Currency::transfer(
+				&buyer,
+				&kitty.breeder,
+				royalty,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::Currency::transfer(
+				&buyer,
+				seller,
+				amount.saturating_sub(fee).saturating_sub(royalty),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::transfer_kitty_to(kitty_id, buyer.clone())?;
+			Self::deposit_event(Event::KittySold(
+				seller.clone(),
+				buyer,
+				kitty_id,
+				amount,
+				fee,
+				royalty,
+			));
+			Ok(())
+		}
+
+		// Remove the offer of `buyer` for `kitty_id` and return its amount to them. It is left in
+		// `OfferExpiries`.
+		fn remove_offer(
+			kitty_id: T::KittyIndex,
+			buyer: &T::AccountId,
+		) -> Result<Offer<T>, Error<T>> {
+			let offer = Offers::<T>::mutate(kitty_id, |offers| {
+				let index = offers.iter().position(|offer| offer.buyer == *buyer)?;
+				Some(offers.swap_remove(index))
+			})
+			.ok_or(Error::<T>::OfferNotExist)?;
+			T::Currency::unreserve(buyer, offer.amount);
+			Ok(offer)
+		}
+
+		fn remove_offer_expiry(kitty_id: T::KittyIndex, offer: &Offer<T>) {
+			OfferExpiries::<T>::mutate(offer.expiry, |expiring| {
+				expiring.retain(|(id, buyer)| *id != kitty_id || *buyer != offer.buyer)
+			});
+		}
+
+		// `amount`, or nothing if it is too small to create the account of `to`, which does not
+		// exist yet.
+		fn payable(to: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			if amount < T::Currency::minimum_balance() && T::Currency::total_balance(to).is_zero()
+			{
+				Zero::zero()
+			} else {
+				amount
+			}
+		}
+
+		// End the auction of `kitty_id`, selling the kitty to the highest bidder of an English
+		// auction if the bid reaches the reserve price. The bid is refunded instead if the
+		// bidder cannot take the kitty, e.g. owning too many already.
+		fn do_settle_auction(kitty_id: T::KittyIndex) -> DispatchResult {
+			let auction = Auctions::<T>::take(kitty_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let mut sale = None;
+			if let Some((bidder, amount)) = auction.best_bid {
+				T::Currency::unreserve(&bidder, amount);
+				if amount >= auction.end_price &&
+					Self::sell_kitty(kitty_id, &auction.seller, bidder.clone(), amount).is_ok()
+				{
+					sale = Some((bidder, amount));
+				}
+			}
+			Self::deposit_event(Event::AuctionSettled(kitty_id, sale));
 			Ok(())
 		}
 
+		/// Price of a Dutch auction at block `now`, going down linearly from the start price to
+		/// the end price.
+		pub fn dutch_price(auction: &Auction<T>, now: T::BlockNumber) -> BalanceOf<T> {
+			let duration = auction.end.saturating_sub(auction.start);
+			let elapsed = now.saturating_sub(auction.start).min(duration);
+			let drop = Perbill::from_rational(elapsed, duration) *
+				auction.start_price.saturating_sub(auction.end_price);
+			auction.start_price.saturating_sub(drop)
+		}
+
+		// Check neither kitty is a parent or sibling of the other, nor resting from breeding.
+		fn ensure_can_mate(
+			(id_1, kitty1): (T::KittyIndex, &Kitty<T>),
+			(id_2, kitty2): (T::KittyIndex, &Kitty<T>),
+		) -> Result<(), Error<T>> {
+			let is_parent_of = |id, kitty: &Kitty<T>| {
+				kitty.parents.map_or(false, |(parent1, parent2)| parent1 == id || parent2 == id)
+			};
+			ensure!(
+				!is_parent_of(id_1, kitty2) && !is_parent_of(id_2, kitty1),
+				Error::<T>::BreedWithParent
+			);
+			if let (Some((a1, a2)), Some((b1, b2))) = (kitty1.parents, kitty2.parents) {
+				ensure!(a1 != b1 && a1 != b2 && a2 != b1 && a2 != b2, Error::<T>::BreedWithSibling);
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				kitty1.next_breed_at <= now && kitty2.next_breed_at <= now,
+				Error::<T>::KittyOnCooldown
+			);
+			Ok(())
+		}
+
+		// Let `kitty` rest from breeding from block `now` on, for `breed_cooldown` blocks.
+		fn start_cooldown(kitty_id: T::KittyIndex, mut kitty: Kitty<T>, now: T::BlockNumber) {
+			kitty.next_breed_at = now.saturating_add(Self::breed_cooldown(&kitty));
+			Kitties::<T>::insert(kitty_id, Some(kitty));
+		}
+
+		/// Number of blocks `kitty` rests after breeding.
+		pub fn breed_cooldown(kitty: &Kitty<T>) -> T::BlockNumber {
+			let index = genetics::decode(&kitty.dna).cooldown;
+			T::BreedCooldown::get().saturating_mul((u32::from(index) + 1).into())
+		}
+
+		/// Traits decoded from the DNA of `kitty_id`, if it exists and has hatched.
+		pub fn kitty_traits(kitty_id: T::KittyIndex) -> Option<KittyTraits> {
+			Self::kitties(kitty_id).map(|kitty| genetics::decode(&kitty.dna))
+		}
+
+		// Number of kitties `who` owns, which calls going through them are weighed by.
+		fn kitties_owned(who: &T::AccountId) -> u32 {
+			KittiesOwner::<T>::decode_len(who).unwrap_or(0) as u32
+		}
+
+		// Weight component of moving a kitty from `from` to `to`: the most kitties either of
+		// them owns besides it.
+		fn kitties_moved_between(from: &T::AccountId, to: &T::AccountId) -> u32 {
+			Self::kitties_owned(from).saturating_sub(1).max(Self::kitties_owned(to))
+		}
+
 		pub fn is_kitty_owner(
 			kitty_id: T::KittyIndex,
 			acct: &T::AccountId,
@@ -277,6 +1049,12 @@ pub mod pallet {
 			kitty.price = None;
 
 			<Kitties<T>>::insert(kitty_id, Some(kitty));
+			SiringOffers::<T>::remove(kitty_id);
+			for offer in Offers::<T>::take(kitty_id) {
+				T::Currency::unreserve(&offer.buyer, offer.amount);
+				Self::remove_offer_expiry(kitty_id, &offer);
+				Self::deposit_event(Event::OfferCancelled(offer.buyer, kitty_id));
+			}
 
 			KittiesOwner::<T>::try_mutate(&to, |vec| vec.try_push(kitty_id))
 				.map_err(|_| <Error<T>>::ExceedKittyOwned)?;
@@ -0,0 +1,75 @@
+//! Storage migrations for the kitties pallet.
+
+use crate::{genetics, AccountOf, BalanceOf, Config, Kitties, Kitty, Pallet};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+	/// `Kitty` before v1, holding only its DNA, price and owner.
+	#[derive(Encode, Decode)]
+	pub struct OldKitty<T: Config> {
+		pub dna: [u8; 16],
+		pub price: Option<BalanceOf<T>>,
+		pub owner: AccountOf<T>,
+	}
+
+	/// Add lineage, breeding cooldown and breeder to every kitty. Existing kitties become
+	/// generation 0 kitties without parents, born at genesis and free to breed. Who created
+	/// them is not known, so their current owners become their breeders.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut migrated = 0u64;
+			Kitties::<T>::translate::<Option<OldKitty<T>>, _>(|_, old| {
+				migrated += 1;
+				Some(old.map(|old| Kitty {
+					dna: genetics::gen_zero(old.dna),
+					price: old.price,
+					breeder: old.owner.clone(),
+					owner: old.owner,
+					parents: None,
+					generation: 0,
+					birth_block: Default::default(),
+					next_breed_at: Default::default(),
+				}))
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			Self::set_temp_storage(Kitties::<T>::iter_keys().count() as u32, "kitty_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"kitties storage was not moved to version 1"
+			);
+			let expected: u32 =
+				Self::get_temp_storage("kitty_count").ok_or("kitty count was not recorded")?;
+			frame_support::ensure!(
+				Kitties::<T>::iter_values().count() as u32 == expected,
+				"kitties were lost in the migration"
+			);
+			Ok(())
+		}
+	}
+}
@@ -5,6 +5,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -22,7 +23,6 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		KittiesModule: pallet_kitties::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
 	}
 );
 
@@ -75,20 +75,38 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Test>;
 }
 
-impl pallet_randomness_collective_flip::Config for Test {}
-
 parameter_types! {
 	pub const StakeForEachKitty: u128 = 10_000;
 	pub const MaxKittyOwned: u32 = 3;
+	pub const EggIncubation: u64 = 3;
+	pub const RevealPeriod: u64 = 5;
+	pub const MaxEggsPerBlock: u32 = 1;
+	pub const BreedCooldown: u64 = 5;
+	pub const MaxAuctionsPerBlock: u32 = 1;
+	pub const MarketplaceFee: Permill = Permill::from_percent(10);
+	pub const Royalty: Permill = Permill::from_percent(5);
+	pub const TreasuryAccount: u64 = 99;
+	pub const MaxOffersPerKitty: u32 = 2;
+	pub const MaxOffersPerBlock: u32 = 2;
 }
 
 impl pallet_kitties::Config for Test {
 	type Event = Event;
-	type Randomness = RandomnessCollectiveFlip;
 	type KittyIndex = u32;
 	type StakeForEachKitty = StakeForEachKitty;
 	type Currency = Balances;
 	type MaxKittyOwned = MaxKittyOwned;
+	type EggIncubation = EggIncubation;
+	type RevealPeriod = RevealPeriod;
+	type MaxEggsPerBlock = MaxEggsPerBlock;
+	type BreedCooldown = BreedCooldown;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type MarketplaceFee = MarketplaceFee;
+	type Royalty = Royalty;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxOffersPerKitty = MaxOffersPerKitty;
+	type MaxOffersPerBlock = MaxOffersPerBlock;
+	type WeightInfo = ();
 }
 
 // Helper macro.
@@ -1,12 +1,74 @@
 use super::*;
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	genetics,
+	migrations::v1::{MigrateToV1, OldKitty},
+	mock::*,
+	weights::WeightInfo,
+	Error,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_core::H256;
+use sp_io::hashing::{blake2_128, blake2_256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchResult,
+};
+
+const SECRET: [u8; 32] = [7; 32];
+
+fn commitment() -> H256 {
+	BlakeTwo256::hash(&SECRET)
+}
+
+// Create a kitty of `who` and hatch it in the next block, then go back to the current one.
+fn create_kitty(who: u64) -> DispatchResult {
+	let kitty_id = KittiesModule::kitties_count();
+	KittiesModule::create(Origin::signed(who), commitment()).map_err(|e| e.error)?;
+	let now = System::block_number();
+	System::set_block_number(now + 1);
+	let hatched = KittiesModule::hatch(Origin::signed(who), kitty_id, SECRET);
+	System::set_block_number(now);
+	hatched
+}
 
 #[test]
 fn create_works() {
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1), commitment()));
+		let egg = Eggs::<Test>::get(0).unwrap();
+		assert_eq!((egg.owner, egg.parents, egg.hatch_at), (1, None, 1));
+		assert_eq!(Balances::reserved_balance(1), StakeForEachKitty::get());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::EggLaid(1, 0, 1)));
+		assert_noop!(
+			KittiesModule::hatch(Origin::signed(1), 0, SECRET),
+			Error::<Test>::EggNotReady
+		);
+
+		System::set_block_number(2);
+		assert_ok!(KittiesModule::hatch(Origin::signed(1), 0, SECRET));
+		let random = (SECRET, H256::default(), 0u32).using_encoded(blake2_256);
+		let dna = genetics::gen_zero(random.using_encoded(blake2_128));
+		let kitty = KittiesModule::kitties(0).unwrap();
+		assert_eq!((kitty.owner, kitty.dna), (1, dna));
+		assert_eq!((kitty.parents, kitty.generation, kitty.birth_block), (None, 0, 1));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::KittyCreated(1, 0)));
+	});
+}
+
+#[test]
+fn calls_are_weighed_by_the_kitties_owned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		let info = KittiesModule::create(Origin::signed(1), commitment()).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::create(1)));
+
+		// Account 1 keeps its egg besides the kitty it gives away, account 2 owns nothing.
+		let info = KittiesModule::transfer(Origin::signed(1), 0, 2).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::transfer(1)));
 	});
 }
 
@@ -16,7 +78,7 @@ fn create_failed_when_kittiescount_overflow() {
 		KittiesCount::<Test>::put(u32::max_value());
 		let account_id: u64 = 1;
 		assert_noop!(
-			KittiesModule::create(Origin::signed(account_id)),
+			KittiesModule::create(Origin::signed(account_id), commitment()),
 			Error::<Test>::KittiesCountOverflow
 		);
 	});
@@ -25,10 +87,13 @@ fn create_failed_when_kittiescount_overflow() {
 #[test]
 fn create_error_when_max_kitty_owned() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_noop!(KittiesModule::create(Origin::signed(1)), Error::<Test>::ExceedKittyOwned);
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_noop!(
+			KittiesModule::create(Origin::signed(1), commitment()),
+			Error::<Test>::ExceedKittyOwned
+		);
 	});
 }
 
@@ -37,7 +102,7 @@ fn create_failed_when_not_enough_balance_for_staking() {
 	new_test_ext().execute_with(|| {
 		let account_id: u64 = 3;
 		assert_noop!(
-			KittiesModule::create(Origin::signed(account_id)),
+			KittiesModule::create(Origin::signed(account_id), commitment()),
 			Error::<Test>::NotEnoughBalanceForStaking
 		);
 	});
@@ -46,31 +111,34 @@ fn create_failed_when_not_enough_balance_for_staking() {
 #[test]
 fn breed_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
-		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
 	});
 }
 
 #[test]
 fn breed_error_when_same_index() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
-		assert_noop!(KittiesModule::breed(Origin::signed(1), 1, 1), Error::<Test>::SameParentIndex);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 1, commitment()),
+			Error::<Test>::SameParentIndex
+		);
 	});
 }
 
 #[test]
 fn breed_error_when_not_enough_balance_for_staking() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::breed(Origin::signed(2), 0, 1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		Balances::make_free_balance_be(&1, StakeForEachKitty::get() - 1);
 		assert_noop!(
-			KittiesModule::breed(Origin::signed(3), 0, 1),
+			KittiesModule::breed(Origin::signed(1), 0, 1, commitment()),
 			Error::<Test>::NotEnoughBalanceForStaking
 		);
 	});
@@ -79,13 +147,11 @@ fn breed_error_when_not_enough_balance_for_staking() {
 #[test]
 fn breed_error_when_exceed_max_kitty_owned() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
 		assert_noop!(
-			KittiesModule::breed(Origin::signed(2), 0, 1),
+			KittiesModule::breed(Origin::signed(1), 0, 1, commitment()),
 			Error::<Test>::ExceedKittyOwned
 		);
 	});
@@ -94,7 +160,7 @@ fn breed_error_when_exceed_max_kitty_owned() {
 #[test]
 fn transfer_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_ok!(KittiesModule::transfer(Origin::signed(1), 0, 2));
 		assert_eq!(KittiesOwner::<Test>::get(2).contains(&0u32), true);
@@ -104,10 +170,10 @@ fn transfer_works() {
 #[test]
 fn transfer_error_when_exceed_max_kitty_owned() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
-		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_ok!(create_kitty(2));
+		assert_ok!(create_kitty(2));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(KittiesModule::transfer(Origin::signed(1), 0, 2),Error::<Test>::ExceedKittyOwned);
 	});
@@ -116,7 +182,7 @@ fn transfer_error_when_exceed_max_kitty_owned() {
 #[test]
 fn transfer_error_when_not_owner() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(KittiesModule::transfer(Origin::signed(2), 0, 1), Error::<Test>::NotOwner);
 	});
@@ -125,7 +191,7 @@ fn transfer_error_when_not_owner() {
 #[test]
 fn transfer_error_when_not_exists() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(
 			KittiesModule::transfer(Origin::signed(1), 1, 3),
@@ -137,7 +203,7 @@ fn transfer_error_when_not_exists() {
 #[test]
 fn transfer_error_when_not_enough_balance_for_staking() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(
 			KittiesModule::transfer(Origin::signed(1), 0, 3),
@@ -149,7 +215,7 @@ fn transfer_error_when_not_enough_balance_for_staking() {
 #[test]
 fn set_price_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(10000u128)));
 	});
@@ -158,7 +224,7 @@ fn set_price_works() {
 #[test]
 fn set_price_error_when_not_exists() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(
 			KittiesModule::set_price(Origin::signed(1), 1, Some(10000u128)),
@@ -170,10 +236,10 @@ fn set_price_error_when_not_exists() {
 #[test]
 fn buy_kitty_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(10000u128)));
-		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 0));
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 0, 10000u128));
 		assert_eq!(KittiesOwner::<Test>::get(2).contains(&0u32), true);
 	});
 }
@@ -181,24 +247,647 @@ fn buy_kitty_works() {
 #[test]
 fn buy_kitty_error_when_not_for_sale() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_noop!(
-			KittiesModule::buy_kitty(Origin::signed(2), 0),
+			KittiesModule::buy_kitty(Origin::signed(2), 0, 10000u128),
 			Error::<Test>::KittyNotForSale
 		);
 	});
 }
 
+#[test]
+fn buy_kitty_error_when_price_above_max() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(10_000)));
+		// The owner raises the price before the purchase goes through.
+		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(50_000)));
+		assert_noop!(
+			KittiesModule::buy_kitty(Origin::signed(2), 0, 10_000),
+			Error::<Test>::PriceAboveMax
+		);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 0, 60_000));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::KittySold(
+			1, 2, 0, 50_000, 5_000, 0,
+		)));
+	});
+}
+
 #[test]
 fn buy_kitty_error_when_not_enough_balance() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(create_kitty(1));
 		// Transfer AccountID 1 to AccountID 2, KittyIndex = 0
 		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(10000u128)));
 		assert_noop!(
-			KittiesModule::buy_kitty(Origin::signed(3), 0),
+			KittiesModule::buy_kitty(Origin::signed(3), 0, 10000u128),
 			Error::<Test>::NotEnoughBalanceForBuying
 		);
 	});
 }
+
+#[test]
+fn buy_kitty_pays_fee_and_royalty() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(10_000)));
+		let free_1 = Balances::free_balance(1);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(2), 0, 10_000));
+		// No royalty when the breeder sells.
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 1_000);
+		assert_eq!(Balances::free_balance(1), free_1 + 9_000 + StakeForEachKitty::get());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::KittySold(
+			1, 2, 0, 10_000, 1_000, 0,
+		)));
+
+		Balances::make_free_balance_be(&4, 1_000_000);
+		assert_ok!(KittiesModule::set_price(Origin::signed(2), 0, Some(20_000)));
+		let free_1 = Balances::free_balance(1);
+		let free_2 = Balances::free_balance(2);
+		assert_ok!(KittiesModule::buy_kitty(Origin::signed(4), 0, 20_000));
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 3_000);
+		assert_eq!(Balances::free_balance(1), free_1 + 1_000);
+		assert_eq!(Balances::free_balance(2), free_2 + 17_000 + StakeForEachKitty::get());
+		assert_eq!(KittiesModule::kitties(0).unwrap().breeder, 1);
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::KittySold(
+			2, 4, 0, 20_000, 2_000, 1_000,
+		)));
+	});
+}
+
+#[test]
+fn breed_lays_egg_that_hatches_later() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+
+		let egg = Eggs::<Test>::get(2).unwrap();
+		assert_eq!((egg.owner, egg.parents, egg.laid_at, egg.hatch_at), (1, Some((0, 1)), 1, 4));
+		assert_eq!(egg.commitment, commitment());
+		assert!(KittiesModule::kitties(2).is_none());
+		assert!(KittiesOwner::<Test>::get(1).contains(&2));
+		assert_eq!(Balances::reserved_balance(1), 3 * StakeForEachKitty::get());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::EggLaid(1, 2, 4)));
+
+		System::set_block_number(4);
+		assert_noop!(
+			KittiesModule::hatch(Origin::signed(1), 2, SECRET),
+			Error::<Test>::EggNotReady
+		);
+		System::set_block_number(5);
+		assert_ok!(KittiesModule::hatch(Origin::signed(1), 2, SECRET));
+		let kitty = KittiesModule::kitties(2).unwrap();
+		assert_eq!(kitty.owner, 1);
+		assert!(Eggs::<Test>::get(2).is_none());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::KittyBred(1, 0, 1, 2)));
+
+		assert_eq!(KittiesModule::kitty_traits(0).unwrap().generation, 0);
+		assert_eq!(KittiesModule::kitty_traits(2).unwrap().generation, 1);
+	});
+}
+
+#[test]
+fn hatch_draws_dna_from_secret_and_block_hash() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+
+		System::set_block_number(5);
+		assert_noop!(
+			KittiesModule::hatch(Origin::signed(1), 2, [8; 32]),
+			Error::<Test>::WrongSecret
+		);
+		frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(1));
+		assert_ok!(KittiesModule::hatch(Origin::signed(2), 2, SECRET));
+
+		let random = (SECRET, H256::repeat_byte(1), 2u32).using_encoded(blake2_256);
+		let [dna_1, dna_2] = [0, 1].map(|id| KittiesModule::kitties(id).unwrap().dna);
+		let kitty = KittiesModule::kitties(2).unwrap();
+		assert_eq!((kitty.owner, kitty.dna), (1, genetics::breed(&dna_1, &dna_2, &random)));
+		assert!(EggDeadlines::<Test>::get(KittiesModule::egg_deadline(4)).is_empty());
+		assert_noop!(
+			KittiesModule::hatch(Origin::signed(1), 2, SECRET),
+			Error::<Test>::EggNotExist
+		);
+	});
+}
+
+#[test]
+fn egg_discarded_when_not_hatched_in_time() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+		let deadline = KittiesModule::egg_deadline(4);
+		assert_eq!(deadline, 4 + RevealPeriod::get() + 1);
+		let free = Balances::free_balance(1);
+
+		System::set_block_number(deadline);
+		KittiesModule::on_initialize(deadline);
+		assert!(Eggs::<Test>::get(2).is_none());
+		assert!(KittiesModule::kitties(2).is_none());
+		assert_eq!(KittiesOwner::<Test>::get(1).into_inner(), vec![0, 1]);
+		// The stake is slashed, not returned.
+		assert_eq!(Balances::reserved_balance(1), 2 * StakeForEachKitty::get());
+		assert_eq!(Balances::free_balance(1), free);
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::EggDiscarded(1, 2)));
+		assert_noop!(
+			KittiesModule::hatch(Origin::signed(1), 2, SECRET),
+			Error::<Test>::EggNotExist
+		);
+	});
+}
+
+#[test]
+fn breed_error_when_too_many_eggs() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_ok!(create_kitty(2));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(2), 2, 3, commitment()),
+			Error::<Test>::TooManyEggs
+		);
+	});
+}
+
+#[test]
+fn genetics_decodes_traits() {
+	let dna = [0x85, 0x02, 0x13, 0x94, 0x07, 0x08, 0, 0, 0, 0, 0, 0, 0x05, 0, 0x02, 0];
+	assert_eq!(
+		genetics::decode(&dna),
+		genetics::KittyTraits { color: 5, pattern: 0x13, eye_shape: 7, generation: 5, cooldown: 2 }
+	);
+
+	let dna = genetics::gen_zero([0xff; 16]);
+	assert_eq!(genetics::decode(&dna).generation, 0);
+	assert_eq!(genetics::decode(&dna).cooldown, 0);
+}
+
+#[test]
+fn genetics_shows_dominant_allele() {
+	// Colors: parent 1 carries dominant 0x81 and recessive 0x01, parent 2 recessive 0x02 twice.
+	let dna_1 = [0x81, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+	let dna_2 = [0x02, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+	// No mutation rolls, and parent 1 passes on its dominant allele, which shows.
+	let mut random = [0xff; 32];
+	random[0] = 0b110;
+	let child = genetics::breed(&dna_1, &dna_2, &random);
+	assert_eq!((child[0], child[1]), (0x81, 0x02));
+
+	// Parent 1 passes on its recessive allele, so the tie-break decides.
+	random[0] = 0b111;
+	let child = genetics::breed(&dna_1, &dna_2, &random);
+	assert_eq!((child[0], child[1]), (0x02, 0x01));
+}
+
+#[test]
+fn genetics_mutates_and_counts_generations() {
+	let dna_1 = genetics::gen_zero([0x01; 16]);
+	let mut dna_2 = genetics::breed(&dna_1, &dna_1, &[0xff; 32]);
+	assert_eq!(genetics::decode(&dna_2).generation, 1);
+	for _ in 0..3 {
+		dna_2 = genetics::breed(&dna_1, &dna_2, &[0xff; 32]);
+	}
+	assert_eq!(genetics::decode(&dna_2).generation, 4);
+	assert_eq!(genetics::decode(&dna_2).cooldown, 2);
+
+	// A mutation roll below `MUTATION_CHANCE` replaces the allele from parent 1.
+	let mut random = [0xff; 32];
+	random[1] = 0;
+	random[3] = 0x7a;
+	let child = genetics::breed(&dna_1, &dna_1, &random);
+	assert!(child[0] == 0x7a || child[1] == 0x7a);
+}
+
+#[test]
+fn breed_records_lineage_and_cooldown() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+
+		let parent = KittiesModule::kitties(0).unwrap();
+		assert_eq!((parent.parents, parent.generation, parent.birth_block), (None, 0, 1));
+		assert_eq!(parent.next_breed_at, 1 + BreedCooldown::get());
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 1, commitment()),
+			Error::<Test>::KittyOnCooldown
+		);
+
+		System::set_block_number(5);
+		assert_ok!(KittiesModule::hatch(Origin::signed(1), 2, SECRET));
+		let child = KittiesModule::kitties(2).unwrap();
+		assert_eq!((child.parents, child.generation, child.birth_block), (Some((0, 1)), 1, 4));
+		assert_eq!(child.next_breed_at, 4);
+
+		System::set_block_number(6);
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 2));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+	});
+}
+
+#[test]
+fn breed_cooldown_grows_with_generation() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		let mut kitty = KittiesModule::kitties(0).unwrap();
+		assert_eq!(KittiesModule::breed_cooldown(&kitty), BreedCooldown::get());
+
+		let ancestor = kitty.dna;
+		for _ in 0..4 {
+			kitty.dna = genetics::breed(&ancestor, &kitty.dna, &[0xff; 32]);
+		}
+		assert_eq!(genetics::decode(&kitty.dna).cooldown, 2);
+		assert_eq!(KittiesModule::breed_cooldown(&kitty), 3 * BreedCooldown::get());
+	});
+}
+
+#[test]
+fn breed_error_with_parent_or_sibling() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+		System::set_block_number(6);
+		assert_ok!(KittiesModule::hatch(Origin::signed(1), 2, SECRET));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 2));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1, commitment()));
+		System::set_block_number(10);
+		assert_ok!(KittiesModule::hatch(Origin::signed(1), 3, SECRET));
+
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 3, commitment()),
+			Error::<Test>::BreedWithParent
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 3, 1, commitment()),
+			Error::<Test>::BreedWithParent
+		);
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 3, 2));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(2), 2, 3, commitment()),
+			Error::<Test>::BreedWithSibling
+		);
+	});
+}
+
+#[test]
+fn breed_error_when_not_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(2), 0, 1, commitment()),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(2), 2, 0, commitment()),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn breed_with_pays_for_siring() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 0, 500));
+		assert_eq!(KittiesModule::siring_offers(0), Some(500));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::SiringOffered(
+			1, 0, 500,
+		)));
+
+		let free_1 = Balances::free_balance(1);
+		let free_2 = Balances::free_balance(2);
+		// The fee may not go above what the breeder agreed to.
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(2), 1, 0, commitment(), 499),
+			Error::<Test>::PriceAboveMax
+		);
+		assert_ok!(KittiesModule::breed_with(Origin::signed(2), 1, 0, commitment(), 500));
+		assert_eq!(Balances::free_balance(1), free_1 + 500);
+		assert_eq!(Balances::free_balance(2), free_2 - 500 - StakeForEachKitty::get());
+		System::assert_has_event(mock::Event::KittiesModule(crate::Event::SiringBought(
+			2, 1, 0, 500,
+		)));
+
+		let egg = Eggs::<Test>::get(2).unwrap();
+		assert_eq!((egg.owner, egg.parents), (2, Some((1, 0))));
+		assert_eq!(KittiesModule::kitties(0).unwrap().owner, 1);
+		// The offer is used up.
+		assert_eq!(KittiesModule::siring_offers(0), None);
+	});
+}
+
+#[test]
+fn breed_with_error_when_not_offered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_ok!(create_kitty(1));
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(2), 1, 0, commitment(), 500),
+			Error::<Test>::KittyNotForSiring
+		);
+		assert_noop!(
+			KittiesModule::offer_siring(Origin::signed(2), 0, 500),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 0, 500));
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(2), 0, 0, commitment(), 500),
+			Error::<Test>::SameParentIndex
+		);
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(2), 2, 0, commitment(), 500),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(1), 2, 0, commitment(), 500),
+			Error::<Test>::BuyerIsOwner
+		);
+
+		assert_ok!(KittiesModule::cancel_siring(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::SiringCancelled(1, 0)));
+		assert_noop!(
+			KittiesModule::breed_with(Origin::signed(2), 1, 0, commitment(), 500),
+			Error::<Test>::KittyNotForSiring
+		);
+		assert_noop!(
+			KittiesModule::cancel_siring(Origin::signed(1), 0),
+			Error::<Test>::KittyNotForSiring
+		);
+
+		// Offers lapse when the kitty changes hands.
+		assert_ok!(KittiesModule::offer_siring(Origin::signed(1), 0, 500));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 0, 2));
+		assert_eq!(KittiesModule::siring_offers(0), None);
+	});
+}
+
+#[test]
+fn english_auction_sells_to_highest_bidder() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(1),
+			0,
+			AuctionKind::English,
+			100,
+			1_000,
+			10
+		));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::AuctionCreated(
+			1,
+			0,
+			AuctionKind::English,
+			11,
+		)));
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 0, 2),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 1, commitment()),
+			Error::<Test>::KittyInAuction
+		);
+
+		assert_noop!(KittiesModule::bid(Origin::signed(2), 0, 50), Error::<Test>::BidTooLow);
+		assert_noop!(KittiesModule::bid(Origin::signed(1), 0, 500), Error::<Test>::BuyerIsOwner);
+		assert_ok!(KittiesModule::bid(Origin::signed(3), 0, 700));
+		assert_noop!(KittiesModule::bid(Origin::signed(2), 0, 700), Error::<Test>::BidTooLow);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 0, 1_500));
+		// The outbid bidder gets their funds back.
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::reserved_balance(2), 1_500);
+		// Only the block the auction ends in settles it.
+		System::set_block_number(10);
+		KittiesModule::on_finalize(10);
+		assert!(KittiesModule::auctions(0).is_some());
+
+		let free_1 = Balances::free_balance(1);
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+		assert_eq!(KittiesModule::kitties(0).unwrap().owner, 2);
+		// The marketplace fee is too small to create the treasury account, so the seller keeps
+		// it.
+		assert_eq!(Balances::free_balance(1), free_1 + 1_500 + StakeForEachKitty::get());
+		assert_eq!(Balances::total_balance(&TreasuryAccount::get()), 0);
+		assert_eq!(Balances::reserved_balance(2), StakeForEachKitty::get());
+		assert!(KittiesModule::auctions(0).is_none());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::AuctionSettled(
+			0,
+			Some((2, 1_500)),
+		)));
+	});
+}
+
+#[test]
+fn create_auction_error_when_too_many_ending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(create_kitty(2));
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(1),
+			0,
+			AuctionKind::English,
+			100,
+			1_000,
+			10
+		));
+		assert_noop!(
+			KittiesModule::create_auction(
+				Origin::signed(2),
+				1,
+				AuctionKind::English,
+				100,
+				1_000,
+				10
+			),
+			Error::<Test>::TooManyAuctionsEnding
+		);
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(2),
+			1,
+			AuctionKind::English,
+			100,
+			1_000,
+			11
+		));
+		assert_eq!(AuctionsEnding::<Test>::get(12).into_inner(), vec![1]);
+	});
+}
+
+#[test]
+fn english_auction_below_reserve_refunds_bid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(1),
+			0,
+			AuctionKind::English,
+			100,
+			1_000,
+			10
+		));
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 0, 600));
+
+		System::set_block_number(11);
+		KittiesModule::on_finalize(11);
+		assert_eq!(KittiesModule::kitties(0).unwrap().owner, 1);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::AuctionSettled(
+			0, None,
+		)));
+		System::set_block_number(12);
+		assert_noop!(
+			KittiesModule::bid(Origin::signed(2), 0, 1_000),
+			Error::<Test>::AuctionNotExist
+		);
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 0, 2));
+	});
+}
+
+#[test]
+fn dutch_auction_sells_at_falling_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 0, AuctionKind::Dutch, 100, 1_000, 10),
+			Error::<Test>::InvalidAuction
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(1), 0, AuctionKind::Dutch, 1_000, 0, 0),
+			Error::<Test>::InvalidAuction
+		);
+		assert_noop!(
+			KittiesModule::create_auction(Origin::signed(2), 0, AuctionKind::Dutch, 1_000, 0, 10),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::create_auction(
+			Origin::signed(1),
+			0,
+			AuctionKind::Dutch,
+			1_000,
+			0,
+			10
+		));
+
+		System::set_block_number(6);
+		let auction = KittiesModule::auctions(0).unwrap();
+		assert_eq!(KittiesModule::dutch_price(&auction, 6), 500);
+		assert_eq!(KittiesModule::dutch_price(&auction, 20), 0);
+		assert_noop!(KittiesModule::bid(Origin::signed(2), 0, 400), Error::<Test>::BidTooLow);
+
+		let free_2 = Balances::free_balance(2);
+		assert_ok!(KittiesModule::bid(Origin::signed(2), 0, 600));
+		assert_eq!(KittiesModule::kitties(0).unwrap().owner, 2);
+		assert_eq!(Balances::free_balance(2), free_2 - 500 - StakeForEachKitty::get());
+		assert!(KittiesModule::auctions(0).is_none());
+		assert!(AuctionsEnding::<Test>::get(11).is_empty());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::AuctionSettled(
+			0,
+			Some((2, 500)),
+		)));
+	});
+}
+
+#[test]
+fn accept_offer_sells_kitty() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		Balances::make_free_balance_be(&4, 1_000_000);
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 0, 10_000, 10));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(4), 0, 20_000, 10));
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::OfferMade(
+			4, 0, 20_000, 10,
+		)));
+		assert_eq!(Balances::reserved_balance(2), 10_000);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(2), 0, 15_000, 10),
+			Error::<Test>::OfferAlreadyExist
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(1), 0, 15_000, 10),
+			Error::<Test>::BuyerIsOwner
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(3), 0, 100, 1),
+			Error::<Test>::OfferExpired
+		);
+		assert_noop!(
+			KittiesModule::make_offer(Origin::signed(3), 0, 100, 5),
+			Error::<Test>::TooManyOffers
+		);
+
+		assert_noop!(KittiesModule::accept_offer(Origin::signed(2), 0, 4), Error::<Test>::NotOwner);
+		assert_ok!(KittiesModule::accept_offer(Origin::signed(1), 0, 4));
+		assert_eq!(KittiesModule::kitties(0).unwrap().owner, 4);
+		assert_eq!(Balances::reserved_balance(4), StakeForEachKitty::get());
+		// The other offer is returned once the kitty changes hands.
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(KittiesModule::offers(0).is_empty());
+		assert!(OfferExpiries::<Test>::get(10).is_empty());
+		System::assert_has_event(mock::Event::KittiesModule(crate::Event::KittySold(
+			1, 4, 0, 20_000, 2_000, 0,
+		)));
+		assert_noop!(
+			KittiesModule::accept_offer(Origin::signed(4), 0, 2),
+			Error::<Test>::OfferNotExist
+		);
+	});
+}
+
+#[test]
+fn offer_is_returned_when_withdrawn_or_expired() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_kitty(1));
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 0, 500, 5));
+		assert_ok!(KittiesModule::withdraw_offer(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(OfferExpiries::<Test>::get(5).is_empty());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::OfferCancelled(2, 0)));
+		assert_noop!(
+			KittiesModule::withdraw_offer(Origin::signed(2), 0),
+			Error::<Test>::OfferNotExist
+		);
+
+		assert_ok!(KittiesModule::make_offer(Origin::signed(2), 0, 500, 5));
+		System::set_block_number(5);
+		KittiesModule::on_initialize(5);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(KittiesModule::offers(0).is_empty());
+		System::assert_last_event(mock::Event::KittiesModule(crate::Event::OfferCancelled(2, 0)));
+	});
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<KittiesModule>();
+		let old = OldKitty::<Test> { dna: [0xff; 16], price: Some(500), owner: 1 };
+		frame_support::storage::unhashed::put(&Kitties::<Test>::hashed_key_for(0), &Some(old));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 1);
+		let kitty = KittiesModule::kitties(0).unwrap();
+		assert_eq!(
+			(kitty.owner, kitty.breeder, kitty.price, kitty.parents, kitty.generation),
+			(1, 1, Some(500), None, 0)
+		);
+		assert_eq!((kitty.birth_block, kitty.next_breed_at), (0, 0));
+		assert_eq!(genetics::decode(&kitty.dna).generation, 0);
+		assert_eq!(kitty.dna[..12], [0xff; 12]);
+	});
+}
@@ -0,0 +1,388 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_kitties.
+//!
+//! These figures are estimates, NOT benchmark results: no benchmark run has produced them yet.
+//! Replace this file with the output of the pallet benchmarks on reference hardware:
+//!
+//! ./target/release/node-kitties benchmark pallet --chain dev --execution wasm --wasm-execution compiled \
+//!     --pallet pallet_kitties --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output ./pallets/kitties/src/weights.rs --template .maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_kitties.
+pub trait WeightInfo {
+	fn create(k: u32, ) -> Weight;
+	fn breed(k: u32, ) -> Weight;
+	fn breed_with(k: u32, ) -> Weight;
+	fn hatch() -> Weight;
+	fn transfer(k: u32, ) -> Weight;
+	fn set_price() -> Weight;
+	fn buy_kitty(k: u32, ) -> Weight;
+	fn offer_siring() -> Weight;
+	fn cancel_siring() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn make_offer() -> Weight;
+	fn accept_offer(k: u32, ) -> Weight;
+	fn withdraw_offer() -> Weight;
+}
+
+/// Weights for pallet_kitties using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn create(k: u32, ) -> Weight {
+		(47_213_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((86_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:2 w:2)
+	// Storage: KittiesModule Auctions (r:2 w:0)
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 2]`.
+	fn breed(k: u32, ) -> Weight {
+		(58_604_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((91_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule Auctions (r:2 w:0)
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 3]`.
+	fn breed_with(k: u32, ) -> Weight {
+		(79_117_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((94_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: KittiesModule Eggs (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:0 w:1)
+	fn hatch() -> Weight {
+		(38_420_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn transfer(k: u32, ) -> Weight {
+		(61_952_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((173_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	fn set_price() -> Weight {
+		(26_035_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn buy_kitty(k: u32, ) -> Weight {
+		(112_486_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((181_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	fn offer_siring() -> Weight {
+		(25_384_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule SiringOffers (r:1 w:1)
+	fn cancel_siring() -> Weight {
+		(26_771_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule AuctionsEnding (r:1 w:1)
+	fn create_auction() -> Weight {
+		(31_548_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule AuctionsEnding (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	fn bid() -> Weight {
+		(121_903_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	fn settle_auction() -> Weight {
+		(118_260_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule OfferExpiries (r:1 w:1)
+	fn make_offer() -> Weight {
+		(44_719_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule OfferExpiries (r:16 w:16)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn accept_offer(k: u32, ) -> Weight {
+		(163_872_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((179_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(T::DbWeight::get().reads(25 as Weight))
+			.saturating_add(T::DbWeight::get().writes(25 as Weight))
+	}
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule OfferExpiries (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		(39_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn create(k: u32, ) -> Weight {
+		(47_213_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((86_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:2 w:2)
+	// Storage: KittiesModule Auctions (r:2 w:0)
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 2]`.
+	fn breed(k: u32, ) -> Weight {
+		(58_604_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((91_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule Auctions (r:2 w:0)
+	// Storage: KittiesModule KittiesCount (r:1 w:1)
+	// Storage: KittiesModule KittiesOwner (r:1 w:1)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Eggs (r:0 w:1)
+	/// The range of component `k` is `[0, 3]`.
+	fn breed_with(k: u32, ) -> Weight {
+		(79_117_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((94_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: KittiesModule Eggs (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: KittiesModule EggDeadlines (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:0 w:1)
+	fn hatch() -> Weight {
+		(38_420_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn transfer(k: u32, ) -> Weight {
+		(61_952_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((173_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	fn set_price() -> Weight {
+		(26_035_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn buy_kitty(k: u32, ) -> Weight {
+		(112_486_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((181_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	fn offer_siring() -> Weight {
+		(25_384_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule SiringOffers (r:1 w:1)
+	fn cancel_siring() -> Weight {
+		(26_771_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule AuctionsEnding (r:1 w:1)
+	fn create_auction() -> Weight {
+		(31_548_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule AuctionsEnding (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	fn bid() -> Weight {
+		(121_903_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: KittiesModule Auctions (r:1 w:1)
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	fn settle_auction() -> Weight {
+		(118_260_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:0)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule OfferExpiries (r:1 w:1)
+	fn make_offer() -> Weight {
+		(44_719_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule Auctions (r:1 w:0)
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: KittiesModule OfferExpiries (r:16 w:16)
+	// Storage: KittiesModule KittiesOwner (r:2 w:2)
+	// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// The range of component `k` is `[0, 4]`.
+	fn accept_offer(k: u32, ) -> Weight {
+		(163_872_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((179_000 as Weight).saturating_mul(k as Weight))
+			.saturating_add(RocksDbWeight::get().reads(25 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(25 as Weight))
+	}
+	// Storage: KittiesModule Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: KittiesModule OfferExpiries (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		(39_106_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -14,7 +14,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -33,7 +36,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -198,8 +201,6 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
-impl pallet_randomness_collective_flip::Config for Runtime {}
-
 impl pallet_aura::Config for Runtime {
 	type AuthorityId = AuraId;
 	type DisabledValidators = ();
@@ -265,16 +266,42 @@ impl pallet_sudo::Config for Runtime {
 parameter_types! {
 	pub const StakeForEachKitty: u128 = 1_000;
 	pub const MaxKittyOwned: u32 = 5;
+	pub const EggIncubation: BlockNumber = 100;
+	// Well below `BlockHashCount`, which the hash eggs draw their DNA from must be within.
+	pub const RevealPeriod: BlockNumber = HOURS;
+	pub const MaxEggsPerBlock: u32 = 64;
+	pub const BreedCooldown: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionsPerBlock: u32 = 64;
+	pub const MarketplaceFee: Permill = Permill::from_percent(2);
+	pub const Royalty: Permill = Permill::from_percent(5);
+	pub const MaxOffersPerKitty: u32 = 16;
+	pub const MaxOffersPerBlock: u32 = 64;
+	// Marketplace fees are paid to the sudo key, the account this chain is run from. A chain
+	// started without one has them build up in the account `pallet_treasury` would hold funds
+	// in, for a treasury added later to take over.
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId =
+		Sudo::key().unwrap_or_else(|| TreasuryPalletId::get().into_account_truncating());
 }
 
 /// Configure the pallet-kitties in pallets/kitties.
 impl pallet_kitties::Config for Runtime {
 	type Event = Event;
-	type Randomness = RandomnessCollectiveFlip;
 	type KittyIndex = u32;
 	type StakeForEachKitty = StakeForEachKitty;
 	type Currency = Balances;
 	type MaxKittyOwned = MaxKittyOwned;
+	type EggIncubation = EggIncubation;
+	type RevealPeriod = RevealPeriod;
+	type MaxEggsPerBlock = MaxEggsPerBlock;
+	type BreedCooldown = BreedCooldown;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type MarketplaceFee = MarketplaceFee;
+	type Royalty = Royalty;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxOffersPerKitty = MaxOffersPerKitty;
+	type MaxOffersPerBlock = MaxOffersPerBlock;
+	type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -284,16 +311,16 @@ construct_runtime!(
 		NodeBlock = opaque::Block,
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
-		System: frame_system,
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip,
-		Timestamp: pallet_timestamp,
-		Aura: pallet_aura,
-		Grandpa: pallet_grandpa,
-		Balances: pallet_balances,
-		TransactionPayment: pallet_transaction_payment,
-		Sudo: pallet_sudo,
+		System: frame_system = 0,
+		// Index 1 was `RandomnessCollectiveFlip`, see `RemoveRandomnessCollectiveFlip`.
+		Timestamp: pallet_timestamp = 2,
+		Aura: pallet_aura = 3,
+		Grandpa: pallet_grandpa = 4,
+		Balances: pallet_balances = 5,
+		TransactionPayment: pallet_transaction_payment = 6,
+		Sudo: pallet_sudo = 7,
 		// Include the custom logic from the pallet-kitties in the runtime.
-		KittiesModule: pallet_kitties,
+		KittiesModule: pallet_kitties = 8,
 	}
 );
 
@@ -318,6 +345,25 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (
+	pallet_kitties::migrations::v1::MigrateToV1<Runtime>,
+	RemoveRandomnessCollectiveFlip,
+);
+
+/// Remove the storage of `RandomnessCollectiveFlip`, dropped from the runtime once kitties
+/// stopped drawing their DNA from it.
+pub struct RemoveRandomnessCollectiveFlip;
+
+impl frame_support::traits::OnRuntimeUpgrade for RemoveRandomnessCollectiveFlip {
+	fn on_runtime_upgrade() -> Weight {
+		frame_support::storage::unhashed::kill(&frame_support::storage::storage_prefix(
+			b"RandomnessCollectiveFlip",
+			b"RandomMaterial",
+		));
+		RocksDbWeight::get().writes(1)
+	}
+}
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -325,6 +371,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -338,7 +385,7 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
-		[pallet_template, TemplateModule]
+		[pallet_kitties, KittiesModule]
 	);
 }
 
@@ -473,6 +520,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_kitties_runtime_api::KittiesApi<Block, u32> for Runtime {
+		fn kitty_traits(kitty_id: u32) -> Option<pallet_kitties::genetics::KittyTraits> {
+			KittiesModule::kitty_traits(kitty_id)
+		}
+
+		fn decode_dna(dna: pallet_kitties::genetics::Dna) -> pallet_kitties::genetics::KittyTraits {
+			pallet_kitties::genetics::decode(&dna)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -0,0 +1,309 @@
+//! The `claim` subcommand, notarizing and verifying files against `pallet_poe` without a
+//! running node.
+
+use crate::chain_spec::ChainSpec;
+use codec::{Decode, Encode};
+use node_poe_runtime::{
+	pallet_poe::{Claim, ClaimHistory, ClaimInfo, HashAlgorithm, OwnershipRecord, Proofs},
+	AccountId, BlockNumber, BuildStorage, Runtime,
+};
+use sp_core::hexdisplay::HexDisplay;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ClaimCmd {
+	/// Print the digest to submit with `create_claim` for a file, and the claim it stands for.
+	Hash(HashCmd),
+
+	/// Check whether a file is notarized in a state exported with `export-state`.
+	Verify(VerifyCmd),
+}
+
+impl ClaimCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		match self {
+			ClaimCmd::Hash(cmd) => cmd.run(),
+			ClaimCmd::Verify(cmd) => cmd.run(),
+		}
+	}
+}
+
+/// Hash algorithms a claim can be made with, as `HashAlgorithm` knows them.
+#[derive(Debug, Clone, Copy, clap::ArgEnum)]
+pub enum Algorithm {
+	Blake2_256,
+	Sha2_256,
+	Keccak256,
+}
+
+impl From<Algorithm> for HashAlgorithm {
+	fn from(algorithm: Algorithm) -> Self {
+		match algorithm {
+			Algorithm::Blake2_256 => HashAlgorithm::Blake2_256,
+			Algorithm::Sha2_256 => HashAlgorithm::Sha2_256,
+			Algorithm::Keccak256 => HashAlgorithm::Keccak256,
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ClaimFile {
+	/// The file to hash.
+	#[clap(parse(from_os_str))]
+	pub file: PathBuf,
+
+	/// Hash algorithm the claim is made with.
+	#[clap(long, arg_enum, default_value = "blake2-256")]
+	pub algorithm: Algorithm,
+}
+
+impl ClaimFile {
+	/// The claim `create_claim` records for the file.
+	fn claim(&self) -> sc_cli::Result<Claim> {
+		let algorithm = HashAlgorithm::from(self.algorithm);
+		let data = std::fs::read(&self.file)?;
+		Ok(Claim { algorithm, digest: algorithm.hash(&data) })
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct HashCmd {
+	#[clap(flatten)]
+	pub file: ClaimFile,
+}
+
+impl HashCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		print!("{}", claim_report(&self.file.claim()?));
+		Ok(())
+	}
+}
+
+/// What `hash` prints for `claim`.
+fn claim_report(claim: &Claim) -> String {
+	[
+		format!("digest:      {:?}", claim.digest),
+		format!("claim:       0x{}", HexDisplay::from(&claim.encode())),
+		format!("storage key: 0x{}", HexDisplay::from(&Proofs::<Runtime>::hashed_key_for(claim))),
+	]
+	.iter()
+	.map(|line| format!("{}\n", line))
+	.collect()
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct VerifyCmd {
+	#[clap(flatten)]
+	pub file: ClaimFile,
+
+	/// Block the file must have been notarized at or before.
+	#[clap(long)]
+	pub block: BlockNumber,
+
+	/// Chain spec holding the state, as written by `export-state`.
+	#[clap(long, parse(from_os_str))]
+	pub state: PathBuf,
+}
+
+impl VerifyCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let claim = self.file.claim()?;
+		let storage = ChainSpec::from_json_file(self.state.clone())?.build_storage()?;
+
+		let value = storage
+			.top
+			.get(&Proofs::<Runtime>::hashed_key_for(&claim))
+			.ok_or_else(|| format!("{:?} is not notarized", self.file.file))?;
+		let info = ClaimInfo::<Runtime>::decode(&mut &value[..])
+			.map_err(|e| format!("Invalid claim in state: {}", e))?;
+		let history = storage
+			.top
+			.get(&ClaimHistory::<Runtime>::hashed_key_for(&claim))
+			.map(|value| Vec::<OwnershipRecord<AccountId, BlockNumber>>::decode(&mut &value[..]))
+			.transpose()
+			.map_err(|e| format!("Invalid claim history in state: {}", e))?
+			.unwrap_or_default();
+
+		let notarized_at = notarized_at(&info, &history);
+		if notarized_at > self.block {
+			return Err(format!(
+				"Claim was notarized at block {}, after block {}",
+				notarized_at, self.block
+			)
+			.into())
+		}
+
+		print!("{}", notarization_report(&self.file.file, &info, notarized_at));
+		Ok(())
+	}
+}
+
+/// Earliest block the claim recorded as `info` is known to have been notarized at: the start of
+/// the oldest ownership record kept in `history`, if any, else the block its current owner
+/// acquired it at.
+fn notarized_at(
+	info: &ClaimInfo<Runtime>,
+	history: &[OwnershipRecord<AccountId, BlockNumber>],
+) -> BlockNumber {
+	history
+		.first()
+		.map_or(info.block_number, |record| record.from.min(info.block_number))
+}
+
+/// What `verify` prints for `file`, notarized as `info` since block `notarized_at`. The owner's
+/// block is the one the current owner acquired the claim at.
+fn notarization_report(
+	file: &Path,
+	info: &ClaimInfo<Runtime>,
+	notarized_at: BlockNumber,
+) -> String {
+	[
+		format!("{:?} is notarized", file),
+		format!("first block: {}", notarized_at),
+		format!("owner:       {}", info.owner),
+		format!("since block: {}", info.block_number),
+		format!("created at:  {}", info.created_at),
+		format!("description: {}", String::from_utf8_lossy(&info.description)),
+		format!("mime type:   {}", String::from_utf8_lossy(&info.mime_type)),
+	]
+	.iter()
+	.map(|line| format!("{}\n", line))
+	.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cli::{Cli, Subcommand};
+	use clap::Parser;
+	use sp_core::crypto::AccountId32;
+
+	fn parse(args: &[&str]) -> Result<ClaimCmd, clap::Error> {
+		let cli = Cli::try_parse_from([&["node-poe", "claim"], args].concat())?;
+		match cli.subcommand {
+			Some(Subcommand::Claim(cmd)) => Ok(cmd),
+			other => panic!("not a claim command: {:?}", other),
+		}
+	}
+
+	#[test]
+	fn hash_defaults_to_blake2_256() {
+		let cmd = match parse(&["hash", "doc.pdf"]).unwrap() {
+			ClaimCmd::Hash(cmd) => cmd,
+			other => panic!("not a hash command: {:?}", other),
+		};
+		assert_eq!(cmd.file.file, PathBuf::from("doc.pdf"));
+		assert!(matches!(cmd.file.algorithm, Algorithm::Blake2_256));
+	}
+
+	#[test]
+	fn verify_parses_algorithm_block_and_state() {
+		let args = [
+			"verify",
+			"doc.pdf",
+			"--algorithm",
+			"keccak256",
+			"--block",
+			"5",
+			"--state",
+			"state.json",
+		];
+		let cmd = match parse(&args).unwrap() {
+			ClaimCmd::Verify(cmd) => cmd,
+			other => panic!("not a verify command: {:?}", other),
+		};
+		assert_eq!(cmd.file.file, PathBuf::from("doc.pdf"));
+		assert!(matches!(cmd.file.algorithm, Algorithm::Keccak256));
+		assert_eq!(cmd.block, 5);
+		assert_eq!(cmd.state, PathBuf::from("state.json"));
+	}
+
+	#[test]
+	fn verify_rejects_bad_arguments() {
+		// The block and the state to check against are required.
+		assert!(parse(&["verify", "doc.pdf", "--block", "5"]).is_err());
+		assert!(parse(&["verify", "doc.pdf", "--state", "state.json"]).is_err());
+		assert!(parse(&[
+			"verify",
+			"doc.pdf",
+			"--block",
+			"5",
+			"--state",
+			"state.json",
+			"--algorithm",
+			"md5"
+		])
+		.is_err());
+	}
+
+	#[test]
+	fn claim_hashes_the_file() {
+		let path = std::env::temp_dir().join(format!("poe-claim-{}", std::process::id()));
+		std::fs::write(&path, b"document").unwrap();
+		let file = ClaimFile { file: path.clone(), algorithm: Algorithm::Sha2_256 };
+		let claim = file.claim();
+		std::fs::remove_file(&path).unwrap();
+
+		assert_eq!(
+			claim.unwrap(),
+			Claim {
+				algorithm: HashAlgorithm::Sha2_256,
+				digest: HashAlgorithm::Sha2_256.hash(b"document")
+			}
+		);
+	}
+
+	#[test]
+	fn claim_report_shows_digest_claim_and_storage_key() {
+		let claim = Claim {
+			algorithm: HashAlgorithm::Blake2_256,
+			digest: HashAlgorithm::Blake2_256.hash(b"document"),
+		};
+		let report = claim_report(&claim);
+		let lines: Vec<_> = report.lines().collect();
+
+		assert_eq!(lines.len(), 3);
+		assert_eq!(lines[0], format!("digest:      {:?}", claim.digest));
+		assert_eq!(lines[1], format!("claim:       0x{}", HexDisplay::from(&claim.encode())));
+		assert!(lines[2].starts_with("storage key: 0x"));
+		assert!(lines[2].ends_with(&format!("{}", HexDisplay::from(&claim.encode()))));
+	}
+
+	fn claim_info(block_number: BlockNumber) -> ClaimInfo<Runtime> {
+		ClaimInfo {
+			owner: AccountId32::new([1; 32]),
+			block_number,
+			created_at: 1_000,
+			deposit: 0,
+			description: b"contract".to_vec().try_into().unwrap(),
+			mime_type: b"application/pdf".to_vec().try_into().unwrap(),
+		}
+	}
+
+	#[test]
+	fn notarized_at_goes_back_to_the_oldest_record() {
+		let record = |from, to| OwnershipRecord { owner: AccountId32::new([2; 32]), from, to };
+
+		assert_eq!(notarized_at(&claim_info(7), &[]), 7);
+		assert_eq!(notarized_at(&claim_info(7), &[record(2, 4), record(4, 7)]), 2);
+		// Revoked at block 3 and notarized again at block 7.
+		assert_eq!(notarized_at(&claim_info(7), &[record(2, 3)]), 2);
+	}
+
+	#[test]
+	fn notarization_report_shows_claim_info() {
+		assert_eq!(
+			notarization_report(Path::new("doc.pdf"), &claim_info(7), 3),
+			format!(
+				"\"doc.pdf\" is notarized\n\
+				 first block: 3\n\
+				 owner:       {}\n\
+				 since block: 7\n\
+				 created at:  1000\n\
+				 description: contract\n\
+				 mime type:   application/pdf\n",
+				AccountId32::new([1; 32]),
+			)
+		);
+	}
+}
@@ -50,4 +50,8 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Notarize and verify files without a running node.
+	#[clap(subcommand)]
+	Claim(crate::claim::ClaimCmd),
 }
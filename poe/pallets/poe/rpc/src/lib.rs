@@ -0,0 +1,142 @@
+//! RPC interface for the poe pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_poe::{Claim, OwnershipRecord};
+use serde::{de::DeserializeOwned, Serialize};
+pub use pallet_poe_runtime_api::PoeApi as PoeRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[cfg(test)]
+mod tests;
+
+#[rpc(server)]
+pub trait PoeApi<BlockHash, AccountId, BlockNumber, ClaimInfo> {
+	/// Claims currently held by `account`.
+	#[method(name = "poe_claimsOf")]
+	fn claims_of(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<Claim>>;
+
+	/// What is recorded on chain about `claim`, if it exists.
+	#[method(name = "poe_claimInfo")]
+	fn claim_info(&self, claim: Claim, at: Option<BlockHash>) -> RpcResult<Option<ClaimInfo>>;
+
+	/// Past owners of `claim`, oldest first, with the blocks each held it for.
+	#[method(name = "poe_claimHistory")]
+	fn claim_history(
+		&self,
+		claim: Claim,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OwnershipRecord<AccountId, BlockNumber>>>;
+
+	/// Whether `proof` shows document `digest` is part of the tree anchored as `root`.
+	#[method(name = "poe_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		root: H256,
+		digest: H256,
+		proof: Vec<H256>,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query claims.
+pub struct Poe<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Poe<C, P> {
+	/// Create new `Poe` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId, BlockNumber, ClaimInfo>
+	PoeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, ClaimInfo> for Poe<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PoeRuntimeApi<Block, AccountId, BlockNumber, ClaimInfo>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+	ClaimInfo: Codec + Serialize + Send + Sync + 'static,
+{
+	fn claims_of(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Claim>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claims_of(&at, account)
+			.map_err(|e| runtime_error("Unable to query claims of account.", e))
+	}
+
+	fn claim_info(
+		&self,
+		claim: Claim,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ClaimInfo>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claim_info(&at, claim).map_err(|e| runtime_error("Unable to query claim info.", e))
+	}
+
+	fn claim_history(
+		&self,
+		claim: Claim,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OwnershipRecord<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.claim_history(&at, claim)
+			.map_err(|e| runtime_error("Unable to query claim history.", e))
+	}
+
+	fn verify_inclusion(
+		&self,
+		root: H256,
+		digest: H256,
+		proof: Vec<H256>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.verify_inclusion(&at, root, digest, proof)
+			.map_err(|e| runtime_error("Unable to verify inclusion proof.", e))
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string())))
+		.into()
+}
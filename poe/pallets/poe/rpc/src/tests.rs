@@ -0,0 +1,122 @@
+use super::*;
+use pallet_poe::HashAlgorithm;
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::{BlockStatus, Info};
+use sp_runtime::{
+	generic::BlockId,
+	testing::{Block as RawBlock, ExtrinsicWrapper, Header},
+	traits::NumberFor,
+};
+
+type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+/// Owner of the only claim the mock runtime knows about.
+const OWNER: u64 = 1;
+
+fn known_claim() -> Claim {
+	Claim { algorithm: HashAlgorithm::Blake2_256, digest: HashAlgorithm::Blake2_256.hash(b"doc") }
+}
+
+/// A client whose runtime holds a single claim, created at block 5 by `OWNER`.
+#[derive(Clone)]
+struct TestClient;
+
+impl ProvideRuntimeApi<Block> for TestClient {
+	type Api = Self;
+
+	fn runtime_api<'a>(&'a self) -> ApiRef<'a, Self::Api> {
+		self.clone().into()
+	}
+}
+
+sp_api::mock_impl_runtime_apis! {
+	impl PoeRuntimeApi<Block, u64, u64, u64> for TestClient {
+		fn claims_of(account: u64) -> Vec<Claim> {
+			if account == OWNER { vec![known_claim()] } else { vec![] }
+		}
+
+		fn claim_info(claim: Claim) -> Option<u64> {
+			if claim == known_claim() { Some(5) } else { None }
+		}
+
+		fn claim_history(claim: Claim) -> Vec<OwnershipRecord<u64, u64>> {
+			if claim == known_claim() {
+				vec![OwnershipRecord { owner: 2, from: 1, to: 5 }]
+			} else {
+				vec![]
+			}
+		}
+
+		fn verify_inclusion(root: H256, digest: H256, proof: Vec<H256>) -> bool {
+			pallet_poe::merkle::verify(&root, &digest, &proof)
+		}
+	}
+}
+
+impl HeaderBackend<Block> for TestClient {
+	fn header(&self, _id: BlockId<Block>) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		Info {
+			best_hash: Default::default(),
+			best_number: 0,
+			genesis_hash: Default::default(),
+			finalized_hash: Default::default(),
+			finalized_number: 0,
+			finalized_state: None,
+			number_leaves: 0,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, _id: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+		Ok(BlockStatus::InChain)
+	}
+
+	fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+		Ok(None)
+	}
+}
+
+fn poe() -> impl PoeApiServer<H256, u64, u64, u64> {
+	Poe::<_, Block>::new(Arc::new(TestClient))
+}
+
+#[test]
+fn claims_of_works() {
+	assert_eq!(poe().claims_of(OWNER, None).unwrap(), vec![known_claim()]);
+	assert_eq!(poe().claims_of(2, Some(Default::default())).unwrap(), vec![]);
+}
+
+#[test]
+fn claim_info_works() {
+	assert_eq!(poe().claim_info(known_claim(), None).unwrap(), Some(5));
+
+	let unknown = Claim { algorithm: HashAlgorithm::Sha2_256, digest: known_claim().digest };
+	assert_eq!(poe().claim_info(unknown, None).unwrap(), None);
+}
+
+#[test]
+fn claim_history_works() {
+	assert_eq!(
+		poe().claim_history(known_claim(), None).unwrap(),
+		vec![OwnershipRecord { owner: 2, from: 1, to: 5 }]
+	);
+}
+
+#[test]
+fn verify_inclusion_works() {
+	let digests =
+		[HashAlgorithm::Blake2_256.hash(b"doc"), HashAlgorithm::Blake2_256.hash(b"other doc")];
+	let root = pallet_poe::merkle::root(&digests).unwrap();
+	let proof = pallet_poe::merkle::proof(&digests, 0).unwrap();
+
+	assert!(poe().verify_inclusion(root, digests[0], proof, None).unwrap());
+	assert!(!poe().verify_inclusion(root, digests[0], vec![], None).unwrap());
+}
@@ -0,0 +1,28 @@
+//! Runtime API definition for the poe pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_poe::{Claim, OwnershipRecord};
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PoeApi<AccountId, BlockNumber, ClaimInfo> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		ClaimInfo: Codec,
+	{
+		/// Claims currently held by `account`.
+		fn claims_of(account: AccountId) -> Vec<Claim>;
+
+		/// What is recorded on chain about `claim`, if it exists.
+		fn claim_info(claim: Claim) -> Option<ClaimInfo>;
+
+		/// Past owners of `claim`, oldest first, with the blocks each held it for.
+		fn claim_history(claim: Claim) -> Vec<OwnershipRecord<AccountId, BlockNumber>>;
+
+		/// Whether `proof` shows document `digest` is part of the tree anchored as `root`.
+		fn verify_inclusion(root: H256, digest: H256, proof: Vec<H256>) -> bool;
+	}
+}
@@ -1,6 +1,36 @@
 use crate::*;
 use frame_benchmarking::{benchmarks, whitelisted_caller, account};
+use frame_support::traits::{Currency, Hooks};
 use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::{Bounded, IdentifyAccount};
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, 0);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+/// Create a claim for a funded whitelisted caller, returning what the claim calls need.
+fn create_default_claim<T: Config>() -> (HashAlgorithm, H256, Claim, T::AccountId) {
+	let algorithm = HashAlgorithm::Blake2_256;
+	let digest = algorithm.hash(b"document");
+	let claim = Claim { algorithm, digest };
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], None).is_ok());
+	(algorithm, digest, claim, caller)
+}
+
+/// `b` distinct claims, as `create_claims` and `revoke_claims` take them.
+fn batch_of<T: Config>(b: u32) -> Vec<(HashAlgorithm, H256)> {
+	(0 .. b)
+		.map(|i| {
+			let algorithm = HashAlgorithm::Blake2_256;
+			(algorithm, algorithm.hash(&i.to_le_bytes()))
+		})
+		.collect()
+}
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -8,31 +38,371 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 
 benchmarks! {
 	create_claim {
-		let d in 0 .. T::MaxClaimLength::get();
-		let claim = vec![0; d as usize];
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, vec![], vec![], Some(expires_at))
 	verify {
-		assert_last_event::<T>(Event::ClaimCreated(caller, claim).into())
+		assert_last_event::<T>(Event::ClaimCreated(caller, claim, vec![], vec![]).into())
 	}
 
 	revoke_claim {
-		let d in 0 .. T::MaxClaimLength::get();
-		let claim = vec![0; d as usize];
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
 		let caller: T::AccountId = whitelisted_caller();
-		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone()).is_ok());
-	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], Some(expires_at)).is_ok());
+		let operator = funded_account::<T>("operator", 0);
+		assert!(Pallet::<T>::approve_operator(RawOrigin::Signed(caller.clone()).into(), operator.clone()).is_ok());
+	}: _(RawOrigin::Signed(operator.clone()), algorithm, digest)
 	verify {
-		assert_last_event::<T>(Event::ClaimRevoked(caller, claim).into())
+		assert_last_event::<T>(Event::ClaimRevoked(caller, claim, operator).into())
+	}
+
+	renew_claim {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], Some(expires_at)).is_ok());
+		let renewed = expires_at + 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, Some(renewed))
+	verify {
+		assert_last_event::<T>(Event::ClaimRenewed(caller, claim, Some(renewed)).into())
 	}
 
 	transfer_claim {
-		let d in 0 .. T::MaxClaimLength::get();
-		let claim = vec![0; d as usize];
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let target = funded_account::<T>("target", 0);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], None).is_ok());
+		let operator = funded_account::<T>("operator", 0);
+		assert!(Pallet::<T>::approve_operator(RawOrigin::Signed(caller.clone()).into(), operator.clone()).is_ok());
+	}: _(RawOrigin::Signed(operator), algorithm, digest, target)
+
+	update_claim_metadata {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], None).is_ok());
+		let metadata = vec![0; T::MaxMetadataLength::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, metadata.clone(), metadata)
+	verify {
+		assert_last_event::<T>(Event::ClaimMetadataUpdated(caller, claim).into())
+	}
+
+	offer_claim {
+		let (algorithm, digest, claim, caller) = create_default_claim::<T>();
+		let target = funded_account::<T>("target", 0);
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, target.clone())
+	verify {
+		assert!(ClaimOffers::<T>::contains_key(&claim));
+	}
+
+	accept_claim {
+		let (algorithm, digest, claim, caller) = create_default_claim::<T>();
+		let target = funded_account::<T>("target", 0);
+		assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, target.clone()).is_ok());
+	}: _(RawOrigin::Signed(target.clone()), algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimOfferAccepted(caller, claim, target).into())
+	}
+
+	reject_claim {
+		let (algorithm, digest, claim, caller) = create_default_claim::<T>();
+		let target = funded_account::<T>("target", 0);
+		assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, target.clone()).is_ok());
+	}: _(RawOrigin::Signed(target.clone()), algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimOfferRejected(caller, claim, target).into())
+	}
+
+	cancel_offer {
+		let (algorithm, digest, claim, caller) = create_default_claim::<T>();
+		let target = funded_account::<T>("target", 0);
+		assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, target.clone()).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimOfferCancelled(caller, claim, target).into())
+	}
+
+	expire_offers {
+		let o in 0 .. T::MaxOffersPerBlock::get();
+		let caller = funded_account::<T>("caller", 0);
+		let target = funded_account::<T>("target", 0);
+		for i in 0 .. o {
+			let algorithm = HashAlgorithm::Blake2_256;
+			let digest = algorithm.hash(&i.to_le_bytes());
+			assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], None).is_ok());
+			assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, target.clone()).is_ok());
+		}
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::OfferDuration::get();
+	}: {
+		Pallet::<T>::on_initialize(expires_at);
+	}
+	verify {
+		assert_eq!(ClaimOffers::<T>::iter().count(), 0);
+	}
+
+	expire_claims {
+		let c in 0 .. T::MaxExpiringPerBlock::get();
+		let caller = funded_account::<T>("caller", 0);
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for i in 0 .. c {
+			let algorithm = HashAlgorithm::Blake2_256;
+			let digest = algorithm.hash(&i.to_le_bytes());
+			assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], Some(expires_at)).is_ok());
+		}
+	}: {
+		Pallet::<T>::on_initialize(expires_at);
+	}
+	verify {
+		assert_eq!(Proofs::<T>::iter().count(), 0);
+	}
+
+	create_claims {
+		let b in 0 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let claims = batch_of::<T>(b);
+	}: _(RawOrigin::Signed(caller.clone()), claims)
+	verify {
+		assert_eq!(ClaimsByOwner::<T>::iter_prefix(&caller).count(), b as usize);
+	}
+
+	revoke_claims {
+		let b in 0 .. T::MaxBatchSize::get();
+		// Combined length of the description and MIME type of every claim in the batch.
+		let l in 0 .. 2 * T::MaxMetadataLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let description = vec![0; l.min(T::MaxMetadataLength::get()) as usize];
+		let mime_type = vec![0; l as usize - description.len()];
+		let claims = batch_of::<T>(b);
+		for (algorithm, digest) in &claims {
+			assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), *algorithm, *digest, description.clone(), mime_type.clone(), None).is_ok());
+		}
+		// Revoked by an operator, so that the operator lookup is included.
+		let operator = funded_account::<T>("operator", 0);
+		assert!(Pallet::<T>::approve_operator(RawOrigin::Signed(caller.clone()).into(), operator.clone()).is_ok());
+	}: _(RawOrigin::Signed(operator), claims)
+	verify {
+		assert_eq!(ClaimsByOwner::<T>::iter_prefix(&caller).count(), 0);
+	}
+
+	create_multi_claim {
+		let s in 1 .. T::MaxSigners::get();
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let signers: Vec<T::AccountId> = (0 .. s).map(|i| account("signer", i, 0)).collect();
+		let deadline = frame_system::Pallet::<T>::block_number() + T::MultiClaimDuration::get();
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, signers, s)
+	verify {
+		assert_last_event::<T>(Event::MultiClaimCreated(caller, claim, s, deadline).into())
+	}
+
+	attest_claim {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let signers: Vec<T::AccountId> = (0 .. T::MaxSigners::get()).map(|i| account("signer", i, 0)).collect();
+		let threshold = signers.len() as u32;
+		assert!(Pallet::<T>::create_multi_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, signers.clone(), threshold).is_ok());
+		// Everyone but the last signer attests up front, so the measured call finalizes the claim.
+		let (last, others) = signers.split_last().unwrap();
+		for signer in others {
+			assert!(Pallet::<T>::attest_claim(RawOrigin::Signed(signer.clone()).into(), algorithm, digest).is_ok());
+		}
+	}: _(RawOrigin::Signed(last.clone()), algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::MultiClaimFinalized(caller, claim).into())
+	}
+
+	discard_multi_claims {
+		let p in 0 .. T::MaxPendingPerBlock::get();
+		let caller = funded_account::<T>("caller", 0);
+		let signer: T::AccountId = account("signer", 0, 0);
+		for i in 0 .. p {
+			let algorithm = HashAlgorithm::Blake2_256;
+			let digest = algorithm.hash(&i.to_le_bytes());
+			assert!(Pallet::<T>::create_multi_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![signer.clone()], 1).is_ok());
+		}
+		let deadline = frame_system::Pallet::<T>::block_number() + T::MultiClaimDuration::get();
+	}: {
+		Pallet::<T>::on_initialize(deadline);
+	}
+	verify {
+		assert_eq!(PendingClaims::<T>::iter().count(), 0);
+	}
+
+	create_claim_for {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let signer = T::BenchmarkHelper::signer();
+		let owner = signer.clone().into_account();
+		let deadline = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let payload = Pallet::<T>::claim_payload(&claim, &owner, 0, deadline);
+		let signature = T::BenchmarkHelper::sign(&signer, &payload);
+	}: _(RawOrigin::Signed(caller.clone()), algorithm, digest, owner.clone(), signature, 0, deadline)
+	verify {
+		assert_last_event::<T>(Event::ClaimCreatedFor(owner, claim, caller).into())
+	}
+
+	freeze_claim {
+		let (algorithm, digest, claim, _) = create_default_claim::<T>();
+	}: _(RawOrigin::Root, algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimFrozen(claim).into())
+	}
+
+	unfreeze_claim {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller).into(), algorithm, digest, vec![], vec![], Some(expires_at)).is_ok());
+		assert!(Pallet::<T>::freeze_claim(RawOrigin::Root.into(), algorithm, digest).is_ok());
+		// Let the expiry pass while the claim is frozen, so that unfreezing queues it again.
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+		Pallet::<T>::on_initialize(expires_at);
+	}: _(RawOrigin::Root, algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimUnfrozen(claim).into());
+		assert_eq!(ClaimExpiries::<T>::get(&claim), Some(expires_at + 1u32.into()));
+	}
+
+	force_revoke_claim {
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), algorithm, digest, vec![], vec![], Some(expires_at)).is_ok());
+		assert!(Pallet::<T>::freeze_claim(RawOrigin::Root.into(), algorithm, digest).is_ok());
+	}: _(RawOrigin::Root, algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::ClaimForceRevoked(caller, claim).into())
+	}
+
+	approve_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone())
+	verify {
+		assert_last_event::<T>(Event::OperatorApproved(caller, operator).into())
+	}
+
+	revoke_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		assert!(Pallet::<T>::approve_operator(RawOrigin::Signed(caller.clone()).into(), operator.clone()).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone())
+	verify {
+		assert_last_event::<T>(Event::OperatorRevoked(caller, operator).into())
+	}
+
+	anchor_root {
+		let root = HashAlgorithm::Blake2_256.hash(b"root");
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), root, u32::MAX)
+	verify {
+		assert_last_event::<T>(Event::RootAnchored(caller, root, u32::MAX).into())
+	}
+
+	revoke_root {
+		let root = HashAlgorithm::Blake2_256.hash(b"root");
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		assert!(Pallet::<T>::anchor_root(RawOrigin::Signed(caller.clone()).into(), root, 1).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), root)
+	verify {
+		assert_last_event::<T>(Event::RootRevoked(caller, root).into())
+	}
+
+	register_namespace {
+		let name = b"acme".to_vec();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), name.clone())
+	verify {
+		assert_last_event::<T>(Event::NamespaceRegistered(caller, name).into())
+	}
+
+	add_namespace_admin {
+		let name = b"acme".to_vec();
+		let owner = funded_account::<T>("owner", 0);
+		assert!(Pallet::<T>::register_namespace(RawOrigin::Signed(owner.clone()).into(), name.clone()).is_ok());
+		let admin: T::AccountId = account("admin", 0, 0);
+	}: _(RawOrigin::Signed(owner), name.clone(), admin.clone())
+	verify {
+		assert_last_event::<T>(Event::NamespaceAdminAdded(name, admin).into())
+	}
+
+	remove_namespace_admin {
+		let name = b"acme".to_vec();
+		let owner = funded_account::<T>("owner", 0);
+		assert!(Pallet::<T>::register_namespace(RawOrigin::Signed(owner.clone()).into(), name.clone()).is_ok());
+		let admin: T::AccountId = account("admin", 0, 0);
+		assert!(Pallet::<T>::add_namespace_admin(RawOrigin::Signed(owner.clone()).into(), name.clone(), admin.clone()).is_ok());
+	}: _(RawOrigin::Signed(owner), name.clone(), admin.clone())
+	verify {
+		assert_last_event::<T>(Event::NamespaceAdminRemoved(name, admin).into())
+	}
+
+	create_namespaced_claim {
+		let name = b"acme".to_vec();
+		let owner = funded_account::<T>("owner", 0);
+		assert!(Pallet::<T>::register_namespace(RawOrigin::Signed(owner.clone()).into(), name.clone()).is_ok());
+		// An admin rather than the owner, so that the admin lookup is included.
 		let caller: T::AccountId = whitelisted_caller();
-		let target: T::AccountId = account("target", 0, 0);
-		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone()).is_ok());
-	}: _(RawOrigin::Signed(caller), claim, target)
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		assert!(Pallet::<T>::add_namespace_admin(RawOrigin::Signed(owner).into(), name.clone(), caller.clone()).is_ok());
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+	}: _(RawOrigin::Signed(caller.clone()), name.clone(), algorithm, digest, vec![], vec![])
+	verify {
+		assert_last_event::<T>(Event::NamespacedClaimCreated(name, caller, claim).into())
+	}
+
+	revoke_namespaced_claim {
+		let name = b"acme".to_vec();
+		let owner = funded_account::<T>("owner", 0);
+		assert!(Pallet::<T>::register_namespace(RawOrigin::Signed(owner.clone()).into(), name.clone()).is_ok());
+		let algorithm = HashAlgorithm::Blake2_256;
+		let digest = algorithm.hash(b"document");
+		let claim = Claim { algorithm, digest };
+		assert!(Pallet::<T>::create_namespaced_claim(RawOrigin::Signed(owner.clone()).into(), name.clone(), algorithm, digest, vec![], vec![]).is_ok());
+		// An admin rather than the claim owner, so that the admin lookup is included.
+		let caller: T::AccountId = whitelisted_caller();
+		assert!(Pallet::<T>::add_namespace_admin(RawOrigin::Signed(owner.clone()).into(), name.clone(), caller.clone()).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), name.clone(), algorithm, digest)
+	verify {
+		assert_last_event::<T>(Event::NamespacedClaimRevoked(name, owner, claim, caller).into())
+	}
 
-	impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
-}
\ No newline at end of file
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
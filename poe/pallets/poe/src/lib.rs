@@ -7,29 +7,250 @@ pub use pallet::*;
 
 #[cfg(test)]
 mod mock;
- 
+
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod merkle;
+pub mod migrations;
 pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
 	pub use frame_support::pallet_prelude::*;
+	use frame_support::transactional;
 	pub use frame_system::pallet_prelude::*;
 	pub use sp_std::prelude::*;
 	pub use crate::weights::WeightInfo;
+	use frame_support::traits::{Currency, ReservableCurrency, Time};
+	use sp_core::H256;
+	use sp_runtime::traits::{IdentifyAccount, One, Saturating, Verify, Zero};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type MomentOf<T> = <<T as Config>::Timestamp as Time>::Moment;
+	pub type NamespaceOf<T> = BoundedVec<u8, <T as Config>::MaxNamespaceLength>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Produces the off-chain signatures `create_claim_for` benchmarks need, since a generic
+	/// signature type gives no way to sign.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Public, Signature> {
+		/// A public key `sign` can sign for.
+		fn signer() -> Public;
+		/// Sign `message` with the key of `signer`.
+		fn sign(signer: &Public, message: &[u8]) -> Signature;
+	}
+
+	/// Hash algorithms a claim digest may be produced with.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum HashAlgorithm {
+		Blake2_256,
+		Sha2_256,
+		Keccak256,
+	}
+
+	impl HashAlgorithm {
+		/// Hash `data` the same way off-chain tooling is expected to before submitting a claim.
+		pub fn hash(&self, data: &[u8]) -> H256 {
+			match self {
+				HashAlgorithm::Blake2_256 => sp_io::hashing::blake2_256(data),
+				HashAlgorithm::Sha2_256 => sp_io::hashing::sha2_256(data),
+				HashAlgorithm::Keccak256 => sp_io::hashing::keccak_256(data),
+			}
+			.into()
+		}
+	}
+
+	/// A notarized document, identified by its digest and the algorithm that produced it.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct Claim {
+		pub algorithm: HashAlgorithm,
+		pub digest: H256,
+	}
+
+	/// Everything recorded on chain about a claim.
+	#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[cfg_attr(
+		feature = "std",
+		serde(bound(
+			serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, \
+				MomentOf<T>: Serialize, BalanceOf<T>: Serialize",
+			deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, \
+				MomentOf<T>: Deserialize<'de>, BalanceOf<T>: Deserialize<'de>"
+		))
+	)]
+	pub struct ClaimInfo<T: Config> {
+		/// Current owner of the claim.
+		pub owner: T::AccountId,
+		/// Block at which `owner` acquired the claim.
+		pub block_number: T::BlockNumber,
+		/// Timestamp of the block the claim was created in.
+		pub created_at: MomentOf<T>,
+		/// Amount reserved while the claim is held, from `owner` or from the relayer in
+		/// `ClaimDepositors`.
+		pub deposit: BalanceOf<T>,
+		/// Description or URI of the notarized document.
+		pub description: BoundedVec<u8, T::MaxMetadataLength>,
+		/// MIME type of the notarized document.
+		pub mime_type: BoundedVec<u8, T::MaxMetadataLength>,
+	}
+
+	/// A claim waiting for enough of its signers to attest it before it enters `Proofs`.
+	#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct PendingClaim<T: Config> {
+		/// Account that proposed the claim and will own it once finalized.
+		pub creator: T::AccountId,
+		/// Accounts allowed to attest the claim.
+		pub signers: BoundedVec<T::AccountId, T::MaxSigners>,
+		/// Number of attestations needed to finalize the claim.
+		pub threshold: u32,
+		/// Signers that attested the claim so far.
+		pub attestors: BoundedVec<T::AccountId, T::MaxSigners>,
+		/// Block at the start of which the claim is discarded if still pending.
+		pub deadline: T::BlockNumber,
+		/// Amount reserved from `creator`, carried over to the claim once finalized.
+		pub deposit: BalanceOf<T>,
+	}
+
+	/// An anchored Merkle root over many document digests, see [`crate::merkle`].
+	#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct RootInfo<T: Config> {
+		/// Account that anchored the root.
+		pub owner: T::AccountId,
+		/// Number of leaves in the tree, which bounds the length of a valid proof.
+		pub leaf_count: u32,
+		/// Block the root was anchored in.
+		pub block_number: T::BlockNumber,
+		/// Timestamp of the block the root was anchored in.
+		pub created_at: MomentOf<T>,
+		/// Amount reserved from `owner` while the root is anchored.
+		pub deposit: BalanceOf<T>,
+	}
+
+	/// A past owner of a claim and the blocks it held the claim for.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct OwnershipRecord<AccountId, BlockNumber> {
+		pub owner: AccountId,
+		/// Block at which `owner` acquired the claim.
+		pub from: BlockNumber,
+		/// Block at which `owner` handed the claim over.
+		pub to: BlockNumber,
+	}
+
+	/// A namespace registered by an organization, holding its own claims apart from `Proofs`.
+	#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct NamespaceInfo<T: Config> {
+		/// Account that registered the namespace and manages its admins.
+		pub owner: T::AccountId,
+		/// Amount reserved from `owner` for the namespace name.
+		pub deposit: BalanceOf<T>,
+	}
+
+	/// A transfer of a claim waiting for the recipient's consent.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimOffer<AccountId, BlockNumber> {
+		/// Owner of the claim when the offer was made.
+		pub from: AccountId,
+		/// Account the claim is offered to.
+		pub to: AccountId,
+		/// Block at the start of which the offer lapses.
+		pub expires_at: BlockNumber,
+	}
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+		// Base amount reserved for every claim, refunded when the claim is revoked,
+		// so that `Proofs` cannot be filled with junk for free.
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+
+		// Additional amount reserved for every byte a claim keeps in storage. What pays for its
+		// `ClaimHistory` records is slashed when the claim is removed, as the records stay.
+		#[pallet::constant]
+		type ClaimByteDeposit: Get<BalanceOf<Self>>;
+
+		// Source of the creation moment recorded for every claim.
+		type Timestamp: Time;
+
+		// Maximum length of a claim description and of its MIME type.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+
+		// Number of blocks a claim offer stays open for the recipient.
+		#[pallet::constant]
+		type OfferDuration: Get<Self::BlockNumber>;
+
+		// Maximum number of claim offers that may lapse in the same block.
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
+
+		// Maximum number of claims `create_claims` and `revoke_claims` handle in one call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		// Maximum number of claims that may expire in the same block.
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+
+		// Maximum number of signers of a multi-signer claim.
 		#[pallet::constant]
-		type MaxClaimLength :Get<u32> ;
-		  
-		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>; 
+		type MaxSigners: Get<u32>;
+
+		// Number of blocks a multi-signer claim may stay pending before it is discarded.
+		#[pallet::constant]
+		type MultiClaimDuration: Get<Self::BlockNumber>;
+
+		// Maximum number of pending multi-signer claims that may reach their deadline in the
+		// same block.
+		#[pallet::constant]
+		type MaxPendingPerBlock: Get<u32>;
+
+		// Maximum number of past owners kept for a claim. The oldest record is dropped when a
+		// transfer would exceed it.
+		#[pallet::constant]
+		type MaxHistoryLength: Get<u32>;
+
+		// Maximum length of a namespace name.
+		#[pallet::constant]
+		type MaxNamespaceLength: Get<u32>;
+
+		// Origin allowed to freeze claims under dispute and to revoke any claim.
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		// Signature an owner gives off-chain for a claim a relayer submits with `create_claim_for`.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		// Public key behind an `OffchainSignature`, identifying the owner's account.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId> + Clone;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
 
 		type WeightInfo: WeightInfo;
 
@@ -37,6 +258,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// The pallet's runtime storage items.
@@ -45,10 +267,138 @@ pub mod pallet {
 	pub type Proofs<T:Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		BoundedVec<u8,T::MaxClaimLength>,
-		(T::AccountId,T::BlockNumber),
+		Claim,
+		ClaimInfo<T>,
 	>;
 
+	/// Index of the claims held by every account, kept in sync with `Proofs`.
+	#[pallet::storage]
+	pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Claim,
+		(),
+	>;
+
+	/// Accounts an owner approved to revoke and transfer its claims on its behalf.
+	#[pallet::storage]
+	pub type Operators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	/// Past owners of every claim, oldest first. The current owner is in `Proofs`. The history
+	/// outlives the claim, so that it can still settle disputes once the claim is removed, and is
+	/// carried on if the claim is created again.
+	#[pallet::storage]
+	pub type ClaimHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Claim,
+		BoundedVec<OwnershipRecord<T::AccountId, T::BlockNumber>, T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	/// Claims frozen by `ForceOrigin`, which their owner can neither transfer nor revoke.
+	#[pallet::storage]
+	pub type FrozenClaims<T: Config> = StorageMap<_, Blake2_128Concat, Claim, ()>;
+
+	/// Pending claim transfers, waiting for the recipient to accept or reject them.
+	#[pallet::storage]
+	pub type ClaimOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, Claim, ClaimOffer<T::AccountId, T::BlockNumber>>;
+
+	/// Claims whose pending offer lapses at the start of the given block.
+	#[pallet::storage]
+	pub type OfferExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<Claim, T::MaxOffersPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Block at the start of which a claim expires, for claims created with an expiry.
+	#[pallet::storage]
+	pub type ClaimExpiries<T: Config> = StorageMap<_, Blake2_128Concat, Claim, T::BlockNumber>;
+
+	/// Claims that expire at the start of the given block.
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<Claim, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Multi-signer claims waiting for attestations.
+	#[pallet::storage]
+	pub type PendingClaims<T: Config> = StorageMap<_, Blake2_128Concat, Claim, PendingClaim<T>>;
+
+	/// Pending multi-signer claims discarded at the start of the given block.
+	#[pallet::storage]
+	pub type PendingDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<Claim, T::MaxPendingPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Signers that attested a finalized multi-signer claim.
+	#[pallet::storage]
+	pub type Attestors<T: Config> =
+		StorageMap<_, Blake2_128Concat, Claim, BoundedVec<T::AccountId, T::MaxSigners>>;
+
+	/// Nonce the next owner-signed claim of an account must carry, so that a signature cannot be
+	/// replayed.
+	#[pallet::storage]
+	pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Relayers holding the deposit of claims they created with `create_claim_for`, until the
+	/// claim is removed or its owner takes the deposit over by transferring or editing it.
+	#[pallet::storage]
+	pub type ClaimDepositors<T: Config> = StorageMap<_, Blake2_128Concat, Claim, T::AccountId>;
+
+	/// Anchored Merkle roots, each standing for a whole tree of claims.
+	#[pallet::storage]
+	pub type Roots<T: Config> = StorageMap<_, Blake2_128Concat, H256, RootInfo<T>>;
+
+	/// Registered namespaces. Claims outside any namespace live in `Proofs`, the global default
+	/// namespace.
+	#[pallet::storage]
+	pub type Namespaces<T: Config> =
+		StorageMap<_, Blake2_128Concat, NamespaceOf<T>, NamespaceInfo<T>>;
+
+	/// Accounts the owner of a namespace allowed to create and revoke claims in it.
+	#[pallet::storage]
+	pub type NamespaceAdmins<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	/// Claims created in a namespace, independent of the same claim in `Proofs` or in any other
+	/// namespace.
+	#[pallet::storage]
+	pub type NamespacedProofs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceOf<T>,
+		Blake2_128Concat,
+		Claim,
+		ClaimInfo<T>,
+	>;
 
 
 	// Pallets use events to inform users when important changes are made.
@@ -56,90 +406,1115 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		ClaimCreated( T::AccountId,Vec<u8>),
-		ClaimRevoked( T::AccountId,Vec<u8>),
-		TransferClaim(T::AccountId,Vec<u8>,T::AccountId),
+		/// A claim was created. [owner, claim, description, mime_type]
+		ClaimCreated( T::AccountId,Claim,Vec<u8>,Vec<u8>),
+		/// A claim was revoked by its owner or one of its operators. [owner, claim, by]
+		ClaimRevoked( T::AccountId,Claim,T::AccountId),
+		/// A claim was transferred by its owner or one of its operators. [owner, claim, dest, by]
+		TransferClaim(T::AccountId,Claim,T::AccountId,T::AccountId),
+		/// The owner replaced the metadata of a claim. [owner, claim]
+		ClaimMetadataUpdated(T::AccountId,Claim),
+		/// The owner offered a claim to another account. [owner, claim, dest, expires_at]
+		ClaimOffered(T::AccountId,Claim,T::AccountId,T::BlockNumber),
+		/// The recipient accepted an offer and now owns the claim. [from, claim, to]
+		ClaimOfferAccepted(T::AccountId,Claim,T::AccountId),
+		/// The recipient rejected an offer. [from, claim, to]
+		ClaimOfferRejected(T::AccountId,Claim,T::AccountId),
+		/// The owner withdrew an offer. [from, claim, to]
+		ClaimOfferCancelled(T::AccountId,Claim,T::AccountId),
+		/// An offer lapsed without an answer. [from, claim, to]
+		ClaimOfferExpired(T::AccountId,Claim,T::AccountId),
+		/// A batch of claims was created. [owner, claims]
+		ClaimsCreated(T::AccountId,Vec<Claim>),
+		/// A batch of claims was revoked by their owner or one of its operators. [by, claims]
+		ClaimsRevoked(T::AccountId,Vec<Claim>),
+		/// A Merkle root was anchored. [owner, root, leaf_count]
+		RootAnchored(T::AccountId,H256,u32),
+		/// An anchored Merkle root was revoked. [owner, root]
+		RootRevoked(T::AccountId,H256),
+		/// The owner changed when a claim expires, `None` meaning never. [owner, claim, expires_at]
+		ClaimRenewed(T::AccountId,Claim,Option<T::BlockNumber>),
+		/// A claim reached its expiry and was removed. [owner, claim]
+		ClaimExpired(T::AccountId,Claim),
+		/// An owner approved an operator for its claims. [owner, operator]
+		OperatorApproved(T::AccountId,T::AccountId),
+		/// An owner withdrew the approval of an operator. [owner, operator]
+		OperatorRevoked(T::AccountId,T::AccountId),
+		/// A multi-signer claim was proposed. [creator, claim, threshold, deadline]
+		MultiClaimCreated(T::AccountId,Claim,u32,T::BlockNumber),
+		/// A signer attested a pending multi-signer claim. [signer, claim]
+		ClaimAttested(T::AccountId,Claim),
+		/// A multi-signer claim reached its threshold and entered `Proofs`. [creator, claim]
+		MultiClaimFinalized(T::AccountId,Claim),
+		/// A multi-signer claim reached its deadline without enough attestations. [creator, claim]
+		MultiClaimDiscarded(T::AccountId,Claim),
+		/// A relayer submitted a claim signed by its owner. [owner, claim, relayer]
+		ClaimCreatedFor(T::AccountId,Claim,T::AccountId),
+		/// A claim was frozen. [claim]
+		ClaimFrozen(Claim),
+		/// A claim was unfrozen. [claim]
+		ClaimUnfrozen(Claim),
+		/// A claim was revoked by `ForceOrigin`. [owner, claim]
+		ClaimForceRevoked(T::AccountId,Claim),
+		/// A namespace was registered. [owner, namespace]
+		NamespaceRegistered(T::AccountId,Vec<u8>),
+		/// The namespace owner added an admin. [namespace, admin]
+		NamespaceAdminAdded(Vec<u8>,T::AccountId),
+		/// The namespace owner removed an admin. [namespace, admin]
+		NamespaceAdminRemoved(Vec<u8>,T::AccountId),
+		/// A claim was created in a namespace. [namespace, owner, claim]
+		NamespacedClaimCreated(Vec<u8>,T::AccountId,Claim),
+		/// A claim was revoked from a namespace. [namespace, owner, claim, by]
+		NamespacedClaimRevoked(Vec<u8>,T::AccountId,Claim,T::AccountId),
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
 		ProofAlreadyExist,
-		ClaimTooLong,
 		ClaimNotExist,
+		/// The signer neither owns the claim nor is an approved operator of its owner, where
+		/// operators are allowed.
 		NotClaimOwner,
+		NotEnoughBalanceForDeposit,
+		/// The description or MIME type exceeds `MaxMetadataLength`.
+		MetadataTooLong,
+		/// The claim already has a pending offer.
+		OfferAlreadyExist,
+		OfferNotExist,
+		/// Only the account a claim is offered to may answer the offer.
+		NotOfferRecipient,
+		/// A claim cannot be offered to its own owner.
+		OfferToSelf,
+		/// Too many offers already lapse in the block this one would.
+		TooManyOffers,
+		/// The batch holds more than `MaxBatchSize` claims.
+		BatchTooLarge,
+		RootAlreadyExist,
+		RootNotExist,
+		/// A Merkle root must stand for at least one leaf.
+		EmptyTree,
+		/// A claim cannot expire at or before the current block.
+		ExpiryInPast,
+		/// Too many claims already expire in the block this one would.
+		TooManyExpiries,
+		/// The account is not an approved operator of the signer.
+		NotOperator,
+		/// The threshold must be at least one and at most the number of signers.
+		InvalidThreshold,
+		/// More signers than `MaxSigners` were listed.
+		TooManySigners,
+		/// An account was listed as a signer more than once.
+		DuplicateSigner,
+		/// Too many multi-signer claims already reach their deadline in the block this one would.
+		TooManyPendingClaims,
+		PendingClaimNotExist,
+		/// The signer is not listed as a signer of the pending claim.
+		NotSigner,
+		AlreadyAttested,
+		/// The owner's signature does not match the claim payload.
+		InvalidSignature,
+		/// The nonce is not the one the owner's next signed claim must carry.
+		InvalidNonce,
+		/// The deadline of the signed claim has passed.
+		SignatureExpired,
+		/// The claim is frozen and can neither be transferred nor revoked by its owner.
+		Frozen,
+		NotFrozen,
+		NamespaceAlreadyExist,
+		NamespaceNotExist,
+		/// The namespace name is empty or longer than `MaxNamespaceLength`.
+		InvalidNamespace,
+		/// The signer neither owns the namespace nor is one of its admins.
+		NotNamespaceAdmin,
 	}
 
 	#[pallet::hooks]
-	impl<T:Config>  Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T:Config>  Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let lapsing = OfferExpiries::<T>::take(n);
+			let count = lapsing.len() as u32;
+			for claim in lapsing {
+				// Offers answered or withdrawn in the meantime are already gone.
+				if let Some(offer) = ClaimOffers::<T>::take(&claim) {
+					Self::deposit_event(Event::ClaimOfferExpired(offer.from, claim, offer.to));
+				}
+			}
+
+			// Revoking or renewing a claim takes it out of the queue, so every claim left here
+			// expires now. Both queues are bounded, which bounds the weight of this hook.
+			let expiring = ExpiryQueue::<T>::take(n);
+			let expired = expiring.len() as u32;
+			for claim in expiring {
+				// A frozen claim is kept for the dispute to be settled. Its expiry stays recorded,
+				// so that `unfreeze_claim` can queue it again.
+				if FrozenClaims::<T>::contains_key(&claim) {
+					continue
+				}
+				ClaimExpiries::<T>::remove(&claim);
+				if let Some(info) = Proofs::<T>::get(&claim) {
+					Self::remove_claim(&claim, &info);
+					Self::deposit_event(Event::ClaimExpired(info.owner, claim));
+				}
+			}
+
+			let overdue = PendingDeadlines::<T>::take(n);
+			let discarded = overdue.len() as u32;
+			for claim in overdue {
+				// Finalized claims are taken out of the queue, so every one left here is overdue.
+				if let Some(pending) = PendingClaims::<T>::take(&claim) {
+					T::Currency::unreserve(&pending.creator, pending.deposit);
+					Self::deposit_event(Event::MultiClaimDiscarded(pending.creator, claim));
+				}
+			}
+
+			T::WeightInfo::expire_offers(count)
+				.saturating_add(T::WeightInfo::expire_claims(expired))
+				.saturating_add(T::WeightInfo::discard_multi_claims(discarded))
+		}
+	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
-		pub fn create_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+		/// Notarize a document by the digest `algorithm` produced for it. Only the digest goes on
+		/// chain, so storage and weight do not depend on the document size.
+		///
+		/// With `expires_at`, the claim is removed and its deposit released at the start of that
+		/// block unless the owner renews it first.
+		#[pallet::weight(T::WeightInfo::create_claim())]
+		#[transactional]
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			description: Vec<u8>,
+			mime_type: Vec<u8>,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
 			// https://docs.substrate.io/v3/runtime/origins
 			let sender = ensure_signed(origin)?;
 
-			let bounded_claim = BoundedVec::<u8,T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
-			ensure!(!Proofs::<T>::contains_key(&bounded_claim),Error::<T>::ProofAlreadyExist);
+			let claim = Claim { algorithm, digest };
+			let bounded_description = Self::bounded_metadata(description.clone())?;
+			let bounded_mime_type = Self::bounded_metadata(mime_type.clone())?;
 
-			Proofs::<T>::insert(
-				&bounded_claim, 
-				(&sender,frame_system::Pallet::<T>::block_number())
-			);
-			Self::deposit_event(Event::ClaimCreated(sender,claim));
+			Self::do_create_claim(&sender, &claim, bounded_description, bounded_mime_type)?;
+			if let Some(expires_at) = expires_at {
+				Self::schedule_expiry(&claim, expires_at)?;
+			}
+			Self::deposit_event(Event::ClaimCreated(sender,claim,description,mime_type));
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::revoke_claim(claim.len() as u32))]
-		pub fn revoke_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+		#[pallet::weight(T::WeightInfo::revoke_claim())]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
 			// https://docs.substrate.io/v3/runtime/origins
 			let sender = ensure_signed(origin)?;
 
-			let bounded_claim = BoundedVec::<u8,T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
-			let (owner,_) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?;
-			ensure!(owner==sender,Error::<T>::NotClaimOwner);
-			
-			Proofs::<T>::remove(
-				&bounded_claim, 
-			);
-			Self::deposit_event(Event::ClaimRevoked(sender,claim));
+			let claim = Claim { algorithm, digest };
+			let info = Self::do_revoke_claim(&sender, &claim)?;
+			Self::deposit_event(Event::ClaimRevoked(info.owner,claim,sender));
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::transfer_claim(claim.len() as u32))]
-		pub fn transfer_claim(origin: OriginFor<T>, claim: Vec<u8>, dest: T::AccountId) -> DispatchResultWithPostInfo {
+		#[pallet::weight(T::WeightInfo::transfer_claim())]
+		pub fn transfer_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
 			// https://docs.substrate.io/v3/runtime/origins
 			let sender = ensure_signed(origin)?;
 
-			let bounded_claim = BoundedVec::<u8,T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
-			let (owner,_) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?;
-			ensure!(owner==sender,Error::<T>::NotClaimOwner);
-			
+			let claim = Claim { algorithm, digest };
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			Self::ensure_owner_or_operator(&sender, &info.owner)?;
+
+			let owner = info.owner.clone();
+			Self::do_transfer(&claim, info, &dest)?;
+			Self::deposit_event(Event::TransferClaim(owner,claim,dest,sender));
+			Ok(().into())
+		}
+
+		/// Replace the description and MIME type of a claim, adjusting the reserved deposit to
+		/// the new metadata size. Only the claim owner may do this.
+		#[pallet::weight(T::WeightInfo::update_claim_metadata())]
+		pub fn update_claim_metadata(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			description: Vec<u8>,
+			mime_type: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let mut info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotClaimOwner);
+			let bounded_description = Self::bounded_metadata(description)?;
+			let bounded_mime_type = Self::bounded_metadata(mime_type)?;
+
+			let new_deposit =
+				Self::claim_deposit(&claim, bounded_description.len() + bounded_mime_type.len())
+					.saturating_add(Self::history_deposit(&claim));
+			if let Some(depositor) = ClaimDepositors::<T>::get(&claim) {
+				// The owner takes the deposit over from the relayer that created the claim.
+				T::Currency::reserve(&sender, new_deposit)
+					.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+				T::Currency::unreserve(&depositor, info.deposit);
+				ClaimDepositors::<T>::remove(&claim);
+			} else if new_deposit > info.deposit {
+				T::Currency::reserve(&sender, new_deposit - info.deposit)
+					.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+			} else {
+				T::Currency::unreserve(&sender, info.deposit - new_deposit);
+			}
+
+			info.description = bounded_description;
+			info.mime_type = bounded_mime_type;
+			info.deposit = new_deposit;
+			Proofs::<T>::insert(&claim, info);
+			Self::deposit_event(Event::ClaimMetadataUpdated(sender,claim));
+			Ok(().into())
+		}
+
+		/// Offer a claim to `dest`, who has `OfferDuration` blocks to accept or reject it. The
+		/// claim stays with its owner until the offer is accepted.
+		#[pallet::weight(T::WeightInfo::offer_claim())]
+		pub fn offer_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotClaimOwner);
+			Self::ensure_not_frozen(&claim)?;
+			ensure!(dest != sender, Error::<T>::OfferToSelf);
+			ensure!(!ClaimOffers::<T>::contains_key(&claim), Error::<T>::OfferAlreadyExist);
+
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::OfferDuration::get());
+			OfferExpiries::<T>::try_mutate(expires_at, |claims| claims.try_push(claim))
+				.map_err(|_| Error::<T>::TooManyOffers)?;
+			ClaimOffers::<T>::insert(
+				&claim,
+				ClaimOffer { from: sender.clone(), to: dest.clone(), expires_at },
+			);
+			Self::deposit_event(Event::ClaimOffered(sender,claim,dest,expires_at));
+			Ok(().into())
+		}
+
+		/// Accept a pending offer, taking over the claim and its deposit.
+		#[pallet::weight(T::WeightInfo::accept_claim())]
+		pub fn accept_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let offer = ClaimOffers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			ensure!(offer.to==sender,Error::<T>::NotOfferRecipient);
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+			Self::do_transfer(&claim, info, &sender)?;
+			Self::deposit_event(Event::ClaimOfferAccepted(offer.from,claim,sender));
+			Ok(().into())
+		}
+
+		/// Reject a pending offer, leaving the claim with its owner.
+		#[pallet::weight(T::WeightInfo::reject_claim())]
+		pub fn reject_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let offer = ClaimOffers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			ensure!(offer.to==sender,Error::<T>::NotOfferRecipient);
+
+			Self::clear_offer(&claim);
+			Self::deposit_event(Event::ClaimOfferRejected(offer.from,claim,sender));
+			Ok(().into())
+		}
+
+		/// Withdraw a pending offer. Only the claim owner may do this.
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let offer = ClaimOffers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			ensure!(offer.from==sender,Error::<T>::NotClaimOwner);
+
+			Self::clear_offer(&claim);
+			Self::deposit_event(Event::ClaimOfferCancelled(sender,claim,offer.to));
+			Ok(().into())
+		}
+
+		/// Create several claims without metadata in one go. Either all of them are created or,
+		/// if any fails, none is.
+		#[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+		#[transactional]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			claims: Vec<(HashAlgorithm, H256)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			let mut created = Vec::with_capacity(claims.len());
+			for (algorithm, digest) in claims {
+				let claim = Claim { algorithm, digest };
+				Self::do_create_claim(&sender, &claim, Default::default(), Default::default())?;
+				created.push(claim);
+			}
+			Self::deposit_event(Event::ClaimsCreated(sender,created));
+			Ok(().into())
+		}
+
+		/// Revoke several claims in one go, each owned by the signer or by an owner that approved
+		/// it as operator. Either all of them are revoked or, if any fails, none is.
+		///
+		/// Weighed for claims with the longest metadata, and refunded for the longest metadata
+		/// actually revoked.
+		#[pallet::weight(
+			T::WeightInfo::revoke_claims(claims.len() as u32, 2 * T::MaxMetadataLength::get())
+		)]
+		#[transactional]
+		pub fn revoke_claims(
+			origin: OriginFor<T>,
+			claims: Vec<(HashAlgorithm, H256)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			let mut revoked = Vec::with_capacity(claims.len());
+			let mut metadata_len = 0;
+			for (algorithm, digest) in claims {
+				let claim = Claim { algorithm, digest };
+				let info = Self::do_revoke_claim(&sender, &claim)?;
+				metadata_len = metadata_len.max(info.description.len() + info.mime_type.len());
+				revoked.push(claim);
+			}
+			let weight = T::WeightInfo::revoke_claims(revoked.len() as u32, metadata_len as u32);
+			Self::deposit_event(Event::ClaimsRevoked(sender,revoked));
+			Ok(Some(weight).into())
+		}
+
+		/// Anchor the Merkle `root` of a tree of `leaf_count` document digests built as described
+		/// in [`crate::merkle`], notarizing every document in it at once. Inclusion of a single
+		/// document is checked later with the `verify_inclusion` runtime API.
+		#[pallet::weight(T::WeightInfo::anchor_root())]
+		pub fn anchor_root(
+			origin: OriginFor<T>,
+			root: H256,
+			leaf_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(leaf_count > 0, Error::<T>::EmptyTree);
+			ensure!(!Roots::<T>::contains_key(&root), Error::<T>::RootAlreadyExist);
+
+			let deposit = Self::root_deposit();
+			T::Currency::reserve(&sender, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+			Roots::<T>::insert(
+				&root,
+				RootInfo {
+					owner: sender.clone(),
+					leaf_count,
+					block_number: frame_system::Pallet::<T>::block_number(),
+					created_at: T::Timestamp::now(),
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::RootAnchored(sender,root,leaf_count));
+			Ok(().into())
+		}
+
+		/// Remove an anchored root and release its deposit. Only the account that anchored it
+		/// may do this.
+		#[pallet::weight(T::WeightInfo::revoke_root())]
+		pub fn revoke_root(origin: OriginFor<T>, root: H256) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let info = Roots::<T>::get(&root).ok_or(Error::<T>::RootNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotClaimOwner);
+
+			T::Currency::unreserve(&sender, info.deposit);
+			Roots::<T>::remove(&root);
+			Self::deposit_event(Event::RootRevoked(sender,root));
+			Ok(().into())
+		}
+
+		/// Move the expiry of a claim to `expires_at`, or drop it with `None` so the claim is kept
+		/// until revoked. Only the claim owner may do this, and not while the claim is frozen.
+		#[pallet::weight(T::WeightInfo::renew_claim())]
+		#[transactional]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotClaimOwner);
+			Self::ensure_not_frozen(&claim)?;
+
+			Self::clear_expiry(&claim);
+			if let Some(expires_at) = expires_at {
+				Self::schedule_expiry(&claim, expires_at)?;
+			}
+			Self::deposit_event(Event::ClaimRenewed(sender,claim,expires_at));
+			Ok(().into())
+		}
+
+		/// Let `operator` revoke and transfer the signer's claims on its behalf, until the approval
+		/// is withdrawn with `revoke_operator`.
+		#[pallet::weight(T::WeightInfo::approve_operator())]
+		pub fn approve_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Operators::<T>::insert(&sender, &operator, ());
+			Self::deposit_event(Event::OperatorApproved(sender,operator));
+			Ok(().into())
+		}
+
+		/// Withdraw the approval given to `operator` with `approve_operator`.
+		#[pallet::weight(T::WeightInfo::revoke_operator())]
+		pub fn revoke_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(Operators::<T>::contains_key(&sender, &operator), Error::<T>::NotOperator);
+
+			Operators::<T>::remove(&sender, &operator);
+			Self::deposit_event(Event::OperatorRevoked(sender,operator));
+			Ok(().into())
+		}
+
+		/// Propose a claim that enters `Proofs`, owned by the signer, only once `threshold` of
+		/// `signers` have attested it with `attest_claim`. It is discarded and its deposit
+		/// released if that does not happen within `MultiClaimDuration` blocks.
+		#[pallet::weight(T::WeightInfo::create_multi_claim(signers.len() as u32))]
+		#[transactional]
+		pub fn create_multi_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			signers: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			ensure!(
+				threshold > 0 && threshold as usize <= signers.len(),
+				Error::<T>::InvalidThreshold
+			);
+			for (i, signer) in signers.iter().enumerate() {
+				ensure!(!signers[..i].contains(signer), Error::<T>::DuplicateSigner);
+			}
+			let signers: BoundedVec<T::AccountId, T::MaxSigners> =
+				signers.try_into().map_err(|_| Error::<T>::TooManySigners)?;
+			ensure!(!Proofs::<T>::contains_key(&claim),Error::<T>::ProofAlreadyExist);
+			ensure!(!PendingClaims::<T>::contains_key(&claim),Error::<T>::ProofAlreadyExist);
+
+			let deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::MultiClaimDuration::get());
+			PendingDeadlines::<T>::try_mutate(deadline, |claims| claims.try_push(claim))
+				.map_err(|_| Error::<T>::TooManyPendingClaims)?;
+			// The attestors are kept along with the claim once finalized, so they are paid for too.
+			let deposit = Self::claim_deposit(&claim, signers.encoded_size())
+				.saturating_add(Self::history_deposit(&claim));
+			T::Currency::reserve(&sender, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+
+			PendingClaims::<T>::insert(
+				&claim,
+				PendingClaim {
+					creator: sender.clone(),
+					signers,
+					threshold,
+					attestors: Default::default(),
+					deadline,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::MultiClaimCreated(sender,claim,threshold,deadline));
+			Ok(().into())
+		}
+
+		/// Attest a pending multi-signer claim the signer is listed for. The attestation that
+		/// reaches the threshold moves the claim into `Proofs`.
+		#[pallet::weight(T::WeightInfo::attest_claim())]
+		pub fn attest_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let mut pending =
+				PendingClaims::<T>::get(&claim).ok_or(Error::<T>::PendingClaimNotExist)?;
+			ensure!(pending.signers.contains(&sender), Error::<T>::NotSigner);
+			ensure!(!pending.attestors.contains(&sender), Error::<T>::AlreadyAttested);
+
+			// Attestors are a subset of the signers, so this cannot overflow.
+			pending.attestors.try_push(sender.clone()).map_err(|_| Error::<T>::TooManySigners)?;
+			Self::deposit_event(Event::ClaimAttested(sender,claim));
+
+			if pending.attestors.len() as u32 >= pending.threshold {
+				Self::finalize_multi_claim(&claim, pending);
+			} else {
+				PendingClaims::<T>::insert(&claim, pending);
+			}
+			Ok(().into())
+		}
+
+		/// Create a claim on behalf of `owner`, who signed the payload built by `claim_payload`
+		/// off-chain, so that an owner without funds can have a relayer submit and pay for it.
+		/// `nonce` must be the owner's current nonce and the call must land no later than block
+		/// `deadline`. The deposit is reserved from the relayer, as the owner may hold nothing to
+		/// reserve, and released to it when the claim is removed.
+		#[pallet::weight(T::WeightInfo::create_claim_for())]
+		pub fn create_claim_for(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			owner: T::AccountId,
+			signature: T::OffchainSignature,
+			nonce: u64,
+			deadline: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			ensure!(
+				deadline >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::SignatureExpired
+			);
+			ensure!(nonce == Nonces::<T>::get(&owner), Error::<T>::InvalidNonce);
+			let payload = Self::claim_payload(&claim, &owner, nonce, deadline);
+			ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidSignature);
+			ensure!(!Proofs::<T>::contains_key(&claim),Error::<T>::ProofAlreadyExist);
+			ensure!(!PendingClaims::<T>::contains_key(&claim),Error::<T>::ProofAlreadyExist);
+
+			let deposit =
+				Self::claim_deposit(&claim, 0).saturating_add(Self::history_deposit(&claim));
+			T::Currency::reserve(&sender, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+
+			Nonces::<T>::insert(&owner, nonce.saturating_add(1));
 			Proofs::<T>::insert(
-				&bounded_claim, 
-				(&dest,frame_system::Pallet::<T>::block_number())
+				&claim,
+				ClaimInfo {
+					owner: owner.clone(),
+					block_number: frame_system::Pallet::<T>::block_number(),
+					created_at: T::Timestamp::now(),
+					deposit,
+					description: Default::default(),
+					mime_type: Default::default(),
+				},
+			);
+			ClaimsByOwner::<T>::insert(&owner, &claim, ());
+			ClaimDepositors::<T>::insert(&claim, &sender);
+			Self::deposit_event(Event::ClaimCreatedFor(owner,claim,sender));
+			Ok(().into())
+		}
+
+		/// Freeze a claim under dispute, so that it can neither be transferred nor revoked by its
+		/// owner, nor expire, until it is unfrozen or force-revoked.
+		#[pallet::weight(T::WeightInfo::freeze_claim())]
+		pub fn freeze_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			ensure!(Proofs::<T>::contains_key(&claim), Error::<T>::ClaimNotExist);
+			Self::ensure_not_frozen(&claim)?;
+
+			FrozenClaims::<T>::insert(&claim, ());
+			Self::deposit_event(Event::ClaimFrozen(claim));
+			Ok(().into())
+		}
+
+		/// Lift the freeze put on a claim with `freeze_claim`. A claim whose expiry passed while
+		/// it was frozen expires at the start of the next block.
+		#[pallet::weight(T::WeightInfo::unfreeze_claim())]
+		pub fn unfreeze_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			ensure!(FrozenClaims::<T>::contains_key(&claim), Error::<T>::NotFrozen);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if ClaimExpiries::<T>::get(&claim).map_or(false, |expires_at| expires_at <= now) {
+				// Already taken out of the queue by `on_initialize`.
+				Self::schedule_expiry(&claim, now.saturating_add(One::one()))?;
+			}
+			FrozenClaims::<T>::remove(&claim);
+			Self::deposit_event(Event::ClaimUnfrozen(claim));
+			Ok(().into())
+		}
+
+		/// Revoke any claim, frozen or not, releasing the deposit to its owner.
+		#[pallet::weight(T::WeightInfo::force_revoke_claim())]
+		pub fn force_revoke_claim(
+			origin: OriginFor<T>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let claim = Claim { algorithm, digest };
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+			FrozenClaims::<T>::remove(&claim);
+			Self::clear_expiry(&claim);
+			Self::remove_claim(&claim, &info);
+			Self::deposit_event(Event::ClaimForceRevoked(info.owner,claim));
+			Ok(().into())
+		}
+
+		/// Register the namespace `name`, owned by the signer, reserving a deposit for the name.
+		/// Claims created in it do not collide with the same claims in `Proofs` or in other
+		/// namespaces.
+		#[pallet::weight(T::WeightInfo::register_namespace())]
+		pub fn register_namespace(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let namespace = Self::namespace_of(name.clone())?;
+			ensure!(!Namespaces::<T>::contains_key(&namespace), Error::<T>::NamespaceAlreadyExist);
+
+			let deposit = Self::byte_deposit(namespace.len());
+			T::Currency::reserve(&sender, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+			Namespaces::<T>::insert(&namespace, NamespaceInfo { owner: sender.clone(), deposit });
+			Self::deposit_event(Event::NamespaceRegistered(sender,name));
+			Ok(().into())
+		}
+
+		/// Let `admin` create and revoke claims in namespace `name`. Only the namespace owner
+		/// may do this.
+		#[pallet::weight(T::WeightInfo::add_namespace_admin())]
+		pub fn add_namespace_admin(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			admin: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let namespace = Self::namespace_of(name.clone())?;
+			let info = Namespaces::<T>::get(&namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotNamespaceAdmin);
+
+			NamespaceAdmins::<T>::insert(&namespace, &admin, ());
+			Self::deposit_event(Event::NamespaceAdminAdded(name,admin));
+			Ok(().into())
+		}
+
+		/// Withdraw the permissions given to `admin` with `add_namespace_admin`. Claims it
+		/// created stay in the namespace.
+		#[pallet::weight(T::WeightInfo::remove_namespace_admin())]
+		pub fn remove_namespace_admin(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			admin: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let namespace = Self::namespace_of(name.clone())?;
+			let info = Namespaces::<T>::get(&namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(info.owner==sender,Error::<T>::NotNamespaceAdmin);
+			ensure!(
+				NamespaceAdmins::<T>::contains_key(&namespace, &admin),
+				Error::<T>::NotNamespaceAdmin
 			);
-			Self::deposit_event(Event::TransferClaim(sender,claim,dest));
+
+			NamespaceAdmins::<T>::remove(&namespace, &admin);
+			Self::deposit_event(Event::NamespaceAdminRemoved(name,admin));
 			Ok(().into())
 		}
+
+		/// Notarize a document in namespace `name`, owned by the signer, who must own the
+		/// namespace or be one of its admins. Otherwise behaves like `create_claim`.
+		#[pallet::weight(T::WeightInfo::create_namespaced_claim())]
+		pub fn create_namespaced_claim(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+			description: Vec<u8>,
+			mime_type: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let namespace = Self::namespace_of(name.clone())?;
+			Self::ensure_namespace_admin(&namespace, &sender)?;
+			let claim = Claim { algorithm, digest };
+			ensure!(
+				!NamespacedProofs::<T>::contains_key(&namespace, &claim),
+				Error::<T>::ProofAlreadyExist
+			);
+			let description = Self::bounded_metadata(description)?;
+			let mime_type = Self::bounded_metadata(mime_type)?;
+
+			let deposit = Self::claim_deposit(&claim, description.len() + mime_type.len());
+			T::Currency::reserve(&sender, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+			NamespacedProofs::<T>::insert(
+				&namespace,
+				&claim,
+				ClaimInfo {
+					owner: sender.clone(),
+					block_number: frame_system::Pallet::<T>::block_number(),
+					created_at: T::Timestamp::now(),
+					deposit,
+					description,
+					mime_type,
+				},
+			);
+			Self::deposit_event(Event::NamespacedClaimCreated(name,sender,claim));
+			Ok(().into())
+		}
+
+		/// Remove a claim from namespace `name` and release its deposit. The claim owner, the
+		/// namespace owner and its admins may do this.
+		#[pallet::weight(T::WeightInfo::revoke_namespaced_claim())]
+		pub fn revoke_namespaced_claim(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			algorithm: HashAlgorithm,
+			digest: H256,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let namespace = Self::namespace_of(name.clone())?;
+			let claim = Claim { algorithm, digest };
+			let info = NamespacedProofs::<T>::get(&namespace, &claim)
+				.ok_or(Error::<T>::ClaimNotExist)?;
+			if info.owner != sender {
+				Self::ensure_namespace_admin(&namespace, &sender)?;
+			}
+
+			T::Currency::unreserve(&info.owner, info.deposit);
+			NamespacedProofs::<T>::remove(&namespace, &claim);
+			Self::deposit_event(Event::NamespacedClaimRevoked(name,info.owner,claim,sender));
+			Ok(().into())
+		}
+	}
+
+	// Helper functions.
+	impl<T: Config> Pallet<T> {
+		/// Amount reserved from the owner of `claim` for as long as it is held, given the
+		/// combined length of its metadata.
+		pub fn claim_deposit(claim: &Claim, metadata_len: usize) -> BalanceOf<T> {
+			Self::byte_deposit(claim.encoded_size() + metadata_len)
+		}
+
+		/// Amount reserved from the owner of `claim`, on top of `claim_deposit`, for the ownership
+		/// records kept about it in `ClaimHistory`.
+		pub fn history_deposit(claim: &Claim) -> BalanceOf<T> {
+			let records = ClaimHistory::<T>::decode_len(claim).unwrap_or(0) as u32;
+			Self::record_deposit().saturating_mul(records.into())
+		}
+
+		/// Amount reserved from the owner of an anchored root.
+		pub fn root_deposit() -> BalanceOf<T> {
+			Self::byte_deposit(H256::len_bytes())
+		}
+
+		/// Whether `proof` shows document `digest` is part of the tree anchored as `root`.
+		pub fn verify_inclusion(root: &H256, digest: &H256, proof: &[H256]) -> bool {
+			Roots::<T>::get(root).map_or(false, |info| {
+				proof.len() <= crate::merkle::max_proof_len(info.leaf_count) &&
+					crate::merkle::verify(root, digest, proof)
+			})
+		}
+
+		/// What is recorded on chain about `claim` in namespace `name`, if it exists there.
+		pub fn namespaced_claim_info(name: Vec<u8>, claim: &Claim) -> Option<ClaimInfo<T>> {
+			let namespace = NamespaceOf::<T>::try_from(name).ok()?;
+			NamespacedProofs::<T>::get(&namespace, claim)
+		}
+
+		/// Claims currently held by `who`.
+		pub fn claims_of(who: &T::AccountId) -> Vec<Claim> {
+			ClaimsByOwner::<T>::iter_key_prefix(who).collect()
+		}
+
+		/// What is recorded on chain about `claim`, if it exists.
+		pub fn claim_info(claim: &Claim) -> Option<ClaimInfo<T>> {
+			Proofs::<T>::get(claim)
+		}
+
+		/// Bytes `owner` signs off-chain to have `claim` created for it with `create_claim_for`.
+		/// They include the genesis hash, so that a signature cannot be replayed on another chain.
+		pub fn claim_payload(
+			claim: &Claim,
+			owner: &T::AccountId,
+			nonce: u64,
+			deadline: T::BlockNumber,
+		) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+			(b"poe/claim", genesis_hash, claim, owner, nonce, deadline).encode()
+		}
+
+		/// Past owners of `claim`, oldest first, as far back as `MaxHistoryLength` allows.
+		pub fn claim_history(claim: &Claim) -> Vec<OwnershipRecord<T::AccountId, T::BlockNumber>> {
+			ClaimHistory::<T>::get(claim).into_inner()
+		}
+
+		/// Signers that attested `claim`, if it was created as a multi-signer claim.
+		pub fn attestors_of(claim: &Claim) -> Vec<T::AccountId> {
+			Attestors::<T>::get(claim).map(|attestors| attestors.into_inner()).unwrap_or_default()
+		}
+
+		/// Whether `operator` may revoke and transfer the claims of `owner`.
+		pub fn is_operator(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+			Operators::<T>::contains_key(owner, operator)
+		}
+
+		/// Check `who` is `owner` or one of its approved operators.
+		fn ensure_owner_or_operator(who: &T::AccountId, owner: &T::AccountId) -> DispatchResult {
+			ensure!(who == owner || Self::is_operator(owner, who), Error::<T>::NotClaimOwner);
+			Ok(())
+		}
+
+		/// Check `namespace` exists and `who` owns it or is one of its admins.
+		fn ensure_namespace_admin(namespace: &NamespaceOf<T>, who: &T::AccountId) -> DispatchResult {
+			let info = Namespaces::<T>::get(namespace).ok_or(Error::<T>::NamespaceNotExist)?;
+			ensure!(
+				info.owner == *who || NamespaceAdmins::<T>::contains_key(namespace, who),
+				Error::<T>::NotNamespaceAdmin
+			);
+			Ok(())
+		}
+
+		fn ensure_not_frozen(claim: &Claim) -> Result<(), Error<T>> {
+			ensure!(!FrozenClaims::<T>::contains_key(claim), Error::<T>::Frozen);
+			Ok(())
+		}
+
+		/// Record `claim` for `owner`, reserving the deposit for it and its metadata.
+		fn do_create_claim(
+			owner: &T::AccountId,
+			claim: &Claim,
+			description: BoundedVec<u8, T::MaxMetadataLength>,
+			mime_type: BoundedVec<u8, T::MaxMetadataLength>,
+		) -> DispatchResult {
+			ensure!(!Proofs::<T>::contains_key(claim),Error::<T>::ProofAlreadyExist);
+			ensure!(!PendingClaims::<T>::contains_key(claim),Error::<T>::ProofAlreadyExist);
+
+			let deposit = Self::claim_deposit(claim, description.len() + mime_type.len())
+				.saturating_add(Self::history_deposit(claim));
+			T::Currency::reserve(owner, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+
+			Proofs::<T>::insert(
+				claim,
+				ClaimInfo {
+					owner: owner.clone(),
+					block_number: frame_system::Pallet::<T>::block_number(),
+					created_at: T::Timestamp::now(),
+					deposit,
+					description,
+					mime_type,
+				},
+			);
+			ClaimsByOwner::<T>::insert(owner, claim, ());
+			Ok(())
+		}
+
+		/// Remove `claim` on behalf of `who`, who must own it or be an operator of its owner, and
+		/// release its deposit. Returns what was recorded about the claim.
+		fn do_revoke_claim(
+			who: &T::AccountId,
+			claim: &Claim,
+		) -> Result<ClaimInfo<T>, DispatchError> {
+			let info = Proofs::<T>::get(claim).ok_or(Error::<T>::ClaimNotExist)?;
+			Self::ensure_owner_or_operator(who, &info.owner)?;
+			Self::ensure_not_frozen(claim)?;
+
+			Self::clear_expiry(claim);
+			Self::remove_claim(claim, &info);
+			Ok(info)
+		}
+
+		/// Drop `claim` from storage, recording its last owner in the history, and release its
+		/// deposit. The history outlives the claim, so the share of the deposit paying for it is
+		/// slashed rather than released.
+		fn remove_claim(claim: &Claim, info: &ClaimInfo<T>) {
+			Self::record_ownership(claim, info);
+			let depositor = ClaimDepositors::<T>::take(claim).unwrap_or_else(|| info.owner.clone());
+			let history_fee = Self::history_deposit(claim).min(info.deposit);
+			let _ = T::Currency::slash_reserved(&depositor, history_fee);
+			T::Currency::unreserve(&depositor, info.deposit - history_fee);
+			Self::clear_offer(claim);
+			Proofs::<T>::remove(claim);
+			ClaimsByOwner::<T>::remove(&info.owner, claim);
+			Attestors::<T>::remove(claim);
+		}
+
+		/// Append the tenure of the current owner of `claim`, ending now, to its history, dropping
+		/// the oldest record if the history is full.
+		fn record_ownership(claim: &Claim, info: &ClaimInfo<T>) {
+			let record = OwnershipRecord {
+				owner: info.owner.clone(),
+				from: info.block_number,
+				to: frame_system::Pallet::<T>::block_number(),
+			};
+			ClaimHistory::<T>::mutate(claim, |history| {
+				if history.is_full() {
+					history.remove(0);
+				}
+				// Room was just made, so this cannot fail.
+				let _ = history.try_push(record);
+			});
+		}
+
+		/// Move a pending multi-signer claim that reached its threshold into `Proofs`, keeping
+		/// the deposit reserved from its creator.
+		fn finalize_multi_claim(claim: &Claim, pending: PendingClaim<T>) {
+			PendingClaims::<T>::remove(claim);
+			PendingDeadlines::<T>::mutate(pending.deadline, |claims| claims.retain(|c| c != claim));
+
+			Proofs::<T>::insert(
+				claim,
+				ClaimInfo {
+					owner: pending.creator.clone(),
+					block_number: frame_system::Pallet::<T>::block_number(),
+					created_at: T::Timestamp::now(),
+					deposit: pending.deposit,
+					description: Default::default(),
+					mime_type: Default::default(),
+				},
+			);
+			ClaimsByOwner::<T>::insert(&pending.creator, claim, ());
+			Attestors::<T>::insert(claim, pending.attestors);
+			Self::deposit_event(Event::MultiClaimFinalized(pending.creator, *claim));
+		}
+
+		/// Hand `claim` over to `dest`, moving the deposit reservation along and dropping any
+		/// pending offer.
+		fn do_transfer(
+			claim: &Claim,
+			mut info: ClaimInfo<T>,
+			dest: &T::AccountId,
+		) -> Result<(), Error<T>> {
+			Self::ensure_not_frozen(claim)?;
+			// `dest` also pays for the record of the current owner, unless it replaces the oldest.
+			let history_len = ClaimHistory::<T>::decode_len(claim).unwrap_or(0) as u32;
+			let deposit = if history_len < T::MaxHistoryLength::get() {
+				info.deposit.saturating_add(Self::record_deposit())
+			} else {
+				info.deposit
+			};
+			T::Currency::reserve(dest, deposit)
+				.map_err(|_| Error::<T>::NotEnoughBalanceForDeposit)?;
+			let depositor = ClaimDepositors::<T>::take(claim).unwrap_or_else(|| info.owner.clone());
+			T::Currency::unreserve(&depositor, info.deposit);
+			Self::clear_offer(claim);
+			ClaimsByOwner::<T>::remove(&info.owner, claim);
+			ClaimsByOwner::<T>::insert(dest, claim, ());
+			Self::record_ownership(claim, &info);
+
+			info.owner = dest.clone();
+			info.block_number = frame_system::Pallet::<T>::block_number();
+			info.deposit = deposit;
+			Proofs::<T>::insert(claim, info);
+			Ok(())
+		}
+
+		/// Drop the pending offer for `claim`, if any, together with its expiry entry.
+		fn clear_offer(claim: &Claim) {
+			if let Some(offer) = ClaimOffers::<T>::take(claim) {
+				OfferExpiries::<T>::mutate(offer.expires_at, |claims| claims.retain(|c| c != claim));
+			}
+		}
+
+		/// Queue `claim` for removal at the start of block `expires_at`.
+		fn schedule_expiry(claim: &Claim, expires_at: T::BlockNumber) -> DispatchResult {
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInPast
+			);
+			ExpiryQueue::<T>::try_mutate(expires_at, |claims| claims.try_push(*claim))
+				.map_err(|_| Error::<T>::TooManyExpiries)?;
+			ClaimExpiries::<T>::insert(claim, expires_at);
+			Ok(())
+		}
+
+		/// Drop the expiry of `claim`, if any, together with its queue entry.
+		fn clear_expiry(claim: &Claim) {
+			if let Some(expires_at) = ClaimExpiries::<T>::take(claim) {
+				ExpiryQueue::<T>::mutate(expires_at, |claims| claims.retain(|c| c != claim));
+			}
+		}
+
+		fn record_deposit() -> BalanceOf<T> {
+			let record_len =
+				OwnershipRecord::<T::AccountId, T::BlockNumber>::max_encoded_len() as u32;
+			T::ClaimByteDeposit::get().saturating_mul(record_len.into())
+		}
+
+		fn byte_deposit(bytes: usize) -> BalanceOf<T> {
+			T::ClaimDeposit::get()
+				.saturating_add(T::ClaimByteDeposit::get().saturating_mul((bytes as u32).into()))
+		}
+
+		fn namespace_of(name: Vec<u8>) -> Result<NamespaceOf<T>, Error<T>> {
+			ensure!(!name.is_empty(), Error::<T>::InvalidNamespace);
+			name.try_into().map_err(|_| Error::<T>::InvalidNamespace)
+		}
+
+		fn bounded_metadata(
+			metadata: Vec<u8>,
+		) -> Result<BoundedVec<u8, T::MaxMetadataLength>, Error<T>> {
+			metadata.try_into().map_err(|_| Error::<T>::MetadataTooLong)
+		}
 	}
 }
@@ -0,0 +1,80 @@
+//! Binary Merkle trees over document digests, as anchored with `anchor_root`.
+//!
+//! Leaves are hashed as `blake2_256(0x00 ++ digest)` and inner nodes as
+//! `blake2_256(0x01 ++ min(a, b) ++ max(a, b))`. The prefixes keep an inner node from being
+//! passed off as a leaf, and sorting each pair means a proof is just the list of siblings from
+//! the leaf up, without left/right flags. A node left without a sibling is carried up to the
+//! next level unchanged.
+
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::prelude::*;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Hash of the leaf for document `digest`.
+pub fn leaf_hash(digest: &H256) -> H256 {
+	let mut data = [0u8; 33];
+	data[0] = LEAF_PREFIX;
+	data[1..].copy_from_slice(digest.as_bytes());
+	blake2_256(&data).into()
+}
+
+/// Hash of the inner node with children `a` and `b`, in either order.
+pub fn node_hash(a: &H256, b: &H256) -> H256 {
+	let (low, high) = if a <= b { (a, b) } else { (b, a) };
+	let mut data = [0u8; 65];
+	data[0] = NODE_PREFIX;
+	data[1..33].copy_from_slice(low.as_bytes());
+	data[33..].copy_from_slice(high.as_bytes());
+	blake2_256(&data).into()
+}
+
+/// Maximum length of a proof in a tree of `leaf_count` leaves.
+pub fn max_proof_len(leaf_count: u32) -> usize {
+	leaf_count.max(1).next_power_of_two().trailing_zeros() as usize
+}
+
+/// Root of the tree over `digests`, or `None` if there are none.
+pub fn root(digests: &[H256]) -> Option<H256> {
+	let mut level: Vec<H256> = digests.iter().map(leaf_hash).collect();
+	while level.len() > 1 {
+		level = next_level(&level);
+	}
+	level.pop()
+}
+
+/// Proof that `digests[index]` is part of the tree over `digests`, or `None` if `index` is out
+/// of range.
+pub fn proof(digests: &[H256], mut index: usize) -> Option<Vec<H256>> {
+	if index >= digests.len() {
+		return None
+	}
+	let mut level: Vec<H256> = digests.iter().map(leaf_hash).collect();
+	let mut proof = Vec::new();
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(index ^ 1) {
+			proof.push(*sibling);
+		}
+		level = next_level(&level);
+		index /= 2;
+	}
+	Some(proof)
+}
+
+/// Whether `proof` leads from document `digest` to `root`.
+pub fn verify(root: &H256, digest: &H256, proof: &[H256]) -> bool {
+	proof.iter().fold(leaf_hash(digest), |node, sibling| node_hash(&node, sibling)) == *root
+}
+
+fn next_level(level: &[H256]) -> Vec<H256> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => node_hash(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks(2) yields one or two nodes; qed"),
+		})
+		.collect()
+}
@@ -0,0 +1,106 @@
+//! Storage migrations for the poe pallet.
+//!
+//! Each `vN` module moves storage from version `N - 1` to `N` and is meant to be listed in the
+//! runtime's `Executive` migrations. A migration only runs when the on-chain storage version is
+//! the one it starts from, so it is safe to leave it in place across upgrades.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub mod v1 {
+	use super::*;
+	use crate::{Claim, ClaimInfo, ClaimsByOwner, HashAlgorithm, Proofs};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+	use frame_support::{storage::migration::storage_key_iter, traits::ReservableCurrency};
+
+	/// `Proofs` values before v1: the owner and the block the claim was created in.
+	pub type OldClaimInfo<T> =
+		(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber);
+
+	/// `Proofs` entries as stored before v1, keyed by the raw claim bytes.
+	fn old_proofs<T: Config>() -> impl Iterator<Item = (Vec<u8>, OldClaimInfo<T>)> {
+		storage_key_iter::<Vec<u8>, OldClaimInfo<T>, Blake2_128Concat>(
+			<Pallet<T> as PalletInfoAccess>::name().as_bytes(),
+			b"Proofs",
+		)
+	}
+
+	/// Re-key `Proofs` from the raw claim bytes to the blake2-256 [`Claim`] of those bytes, and
+	/// move its values from the `(owner, block_number)` tuple to [`ClaimInfo`].
+	///
+	/// Existing claims get empty metadata, a zero creation moment and the deposit `create_claim`
+	/// reserves for a claim without metadata, or none if the owner cannot afford it. They are
+	/// also added to the `ClaimsByOwner` index.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// Drain everything before inserting, so the new keys are not mistaken for old ones.
+			let old = old_proofs::<T>().drain().collect::<Vec<_>>();
+			let migrated = old.len() as u64;
+			for (bytes, (owner, block_number)) in old {
+				let algorithm = HashAlgorithm::Blake2_256;
+				let claim = Claim { algorithm, digest: algorithm.hash(&bytes) };
+				let deposit = Pallet::<T>::claim_deposit(&claim, 0);
+				let deposit =
+					T::Currency::reserve(&owner, deposit).map(|_| deposit).unwrap_or_default();
+
+				ClaimsByOwner::<T>::insert(&owner, &claim, ());
+				Proofs::<T>::insert(
+					&claim,
+					ClaimInfo {
+						owner,
+						block_number,
+						created_at: Default::default(),
+						deposit,
+						description: Default::default(),
+						mime_type: Default::default(),
+					},
+				);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(2 * migrated + 1, 4 * migrated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"poe storage is not at version 0"
+			);
+			Self::set_temp_storage(old_proofs::<T>().count() as u32, "poe_claim_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"poe storage was not moved to version 1"
+			);
+			let expected: u32 =
+				Self::get_temp_storage("poe_claim_count").ok_or("claim count was not recorded")?;
+			let mut count = 0u32;
+			for (claim, info) in Proofs::<T>::iter() {
+				frame_support::ensure!(
+					ClaimsByOwner::<T>::contains_key(&info.owner, &claim),
+					"migrated claim is missing from ClaimsByOwner"
+				);
+				count += 1;
+			}
+			frame_support::ensure!(count == expected, "claims were lost in the migration");
+			Ok(())
+		}
+	}
+}
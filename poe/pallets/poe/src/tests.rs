@@ -1,40 +1,125 @@
-use crate::{mock::*, Error, Config, Proofs};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+	mock::*, Attestors, Claim, ClaimDepositors, ClaimExpiries, ClaimInfo, ClaimOffer, ClaimOffers, ClaimsByOwner, Error, Event as PoeEvent, FrozenClaims,
+	ExpiryQueue, HashAlgorithm, NamespaceAdmins, NamespaceOf, NamespacedProofs, Namespaces, Nonces, OfferExpiries, OwnershipRecord, Operators, PendingClaims, PendingDeadlines, Proofs, Roots,
+};
+use crate::{merkle, migrations::v1::MigrateToV1, weights::WeightInfo};
+use codec::Encode;
+use sp_runtime::testing::TestSignature;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::{KeyPrefixIterator, StoragePrefixedMap},
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+	Blake2_128Concat, StorageHasher,
+};
+use sp_core::H256;
+
+fn digest_of(data: &[u8]) -> H256 {
+	HashAlgorithm::Blake2_256.hash(data)
+}
+
+fn claim_of(data: &[u8]) -> Claim {
+	Claim { algorithm: HashAlgorithm::Blake2_256, digest: HashAlgorithm::Blake2_256.hash(data) }
+}
 
 #[test]
 fn create_claim_works() {
 	new_test_ext().execute_with(|| {
 
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		let bounded_claim = BoundedVec::<u8,<Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
 
-		assert_eq!(Proofs::<Test>::get(&bounded_claim),
-		Some((1,frame_system::Pallet::<Test>::block_number())));
+		let info = Proofs::<Test>::get(&claim_of(b"document")).unwrap();
+		assert_eq!(info.owner, 1);
+		assert_eq!(info.block_number, frame_system::Pallet::<Test>::block_number());
 	});
 }
 
 #[test]
-fn create_claim_failed_when_claim_too_long() {
+fn create_claim_records_metadata_and_timestamp() {
 	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(42);
+		assert_ok!(PoeModule::create_claim(
+			Origin::signed(1),
+			HashAlgorithm::Blake2_256,
+			digest_of(b"document"),
+			b"ipfs://doc".to_vec(),
+			b"text/plain".to_vec(),
+			None
+		));
+
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 20);
+		assert_eq!(
+			Proofs::<Test>::get(&claim_of(b"document")),
+			Some(ClaimInfo {
+				owner: 1,
+				block_number: 1,
+				created_at: 42,
+				deposit,
+				description: b"ipfs://doc".to_vec().try_into().unwrap(),
+				mime_type: b"text/plain".to_vec().try_into().unwrap(),
+			})
+		);
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimCreated(
+			1,
+			claim_of(b"document"),
+			b"ipfs://doc".to_vec(),
+			b"text/plain".to_vec(),
+		)));
+	});
+}
 
-		let claim = vec![0,1,2,3];
+#[test]
+fn create_claim_failed_when_metadata_too_long() {
+	new_test_ext().execute_with(|| {
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
-			Error::<Test>::ClaimTooLong	
+			PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document"), vec![0; 17], vec![], None),
+			Error::<Test>::MetadataTooLong
 		);
 	});
 }
 
+#[test]
+fn create_claim_works_for_every_algorithm() {
+	new_test_ext().execute_with(|| {
+		for algorithm in [HashAlgorithm::Blake2_256, HashAlgorithm::Sha2_256, HashAlgorithm::Keccak256] {
+			let digest = algorithm.hash(b"document");
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), algorithm, digest, vec![], vec![], None));
+			assert!(Proofs::<Test>::contains_key(&Claim { algorithm, digest }));
+		}
+	});
+}
+
 #[test]
 fn create_claim_failed_when_claim_alread_exist() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None),
+			Error::<Test>::ProofAlreadyExist
+		);
+	});
+}
+
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0);
+		assert_eq!(deposit, 1_000 + 10 * 33);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document"), vec![], vec![], None));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+	});
+}
 
+#[test]
+fn create_claim_failed_when_not_enough_balance_for_deposit() {
+	new_test_ext().execute_with(|| {
 		assert_noop!(
-			PoeModule::create_claim(Origin::signed(1), claim.clone()),
-			Error::<Test>::ProofAlreadyExist	
+			PoeModule::create_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest_of(b"document"), vec![], vec![], None),
+			Error::<Test>::NotEnoughBalanceForDeposit
 		);
 	});
 }
@@ -42,27 +127,33 @@ fn create_claim_failed_when_claim_alread_exist() {
 #[test]
 fn revoke_claim_works() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		let bounded_claim = BoundedVec::<u8,<Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
 
-		assert_ok!(PoeModule::revoke_claim(Origin::signed(1),bounded_claim.to_vec()));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
 	});
 }
 #[test]
 fn revoke_claim_failed_when_claim_not_exist() {
 	new_test_ext().execute_with(|| {
-		assert_noop!(PoeModule::revoke_claim(Origin::signed(1),vec![1]),Error::<Test>::ClaimNotExist);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document")),
+			Error::<Test>::ClaimNotExist
+		);
 	});
 }
 
 #[test]
 fn revoke_claim_failed_when_not_claim_owner() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		let bounded_claim = BoundedVec::<u8,<Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
-		assert_noop!(PoeModule::revoke_claim(Origin::signed(2),bounded_claim.to_vec()),Error::<Test>::NotClaimOwner);
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotClaimOwner
+		);
 	});
 }
 
@@ -70,32 +161,1249 @@ fn revoke_claim_failed_when_not_claim_owner() {
 #[test]
 fn transfer_claim_works() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		let bounded_claim = BoundedVec::<u8,<Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
-		assert_eq!(Proofs::<Test>::get(&bounded_claim),
-		Some((1,frame_system::Pallet::<Test>::block_number())));
-		assert_ok!(PoeModule::transfer_claim(Origin::signed(1),bounded_claim.to_vec(),2));
-		assert_eq!(Proofs::<Test>::get(&bounded_claim),
-		Some((2,frame_system::Pallet::<Test>::block_number())));
-		
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 1);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// The new owner also pays for the record of the previous one.
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0) +
+			PoeModule::history_deposit(&claim_of(b"document"));
+		assert!(PoeModule::history_deposit(&claim_of(b"document")) > 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().deposit, deposit);
+
 	});
 }
 
 #[test]
 fn  transfer_claim_failed_when_claim_not_exist() {
 	new_test_ext().execute_with(|| {
-		assert_noop!(PoeModule::transfer_claim(Origin::signed(1),vec![1],2),Error::<Test>::ClaimNotExist);
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document"), 2),
+			Error::<Test>::ClaimNotExist
+		);
 	});
 }
 
 #[test]
 fn  transfer_claim_failed_when_not_claim_owner() {
 	new_test_ext().execute_with(|| {
-		let claim = vec![0,1];
-		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-		let bounded_claim = BoundedVec::<u8,<Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
-		assert_ok!(PoeModule::transfer_claim(Origin::signed(1),bounded_claim.to_vec(),2));
-		assert_noop!(PoeModule::transfer_claim(Origin::signed(1),bounded_claim.to_vec(),3),Error::<Test>::NotClaimOwner);
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 3),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_failed_when_dest_cannot_cover_deposit() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 3),
+			Error::<Test>::NotEnoughBalanceForDeposit
+		);
+	});
+}
+
+#[test]
+fn update_claim_metadata_works() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+
+		assert_ok!(PoeModule::update_claim_metadata(
+			Origin::signed(1),
+			HashAlgorithm::Blake2_256,
+			digest,
+			b"ipfs://doc".to_vec(),
+			b"text/plain".to_vec()
+		));
+		let info = Proofs::<Test>::get(&claim_of(b"document")).unwrap();
+		assert_eq!(info.description.to_vec(), b"ipfs://doc".to_vec());
+		assert_eq!(info.mime_type.to_vec(), b"text/plain".to_vec());
+		assert_eq!(Balances::reserved_balance(1), PoeModule::claim_deposit(&claim_of(b"document"), 20));
+
+		// Shrinking the metadata releases the extra deposit again.
+		assert_ok!(PoeModule::update_claim_metadata(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![]));
+		assert_eq!(Balances::reserved_balance(1), PoeModule::claim_deposit(&claim_of(b"document"), 0));
+	});
+}
+
+#[test]
+fn update_claim_metadata_failed_when_not_claim_owner() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_noop!(
+			PoeModule::update_claim_metadata(Origin::signed(2), HashAlgorithm::Blake2_256, digest, vec![1], vec![]),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+fn put_v0_claim(data: &[u8], owner: u64, block_number: u64) {
+	frame_support::storage::migration::put_storage_value(
+		b"PoeModule",
+		b"Proofs",
+		&Blake2_128Concat::hash(&data.to_vec().encode()),
+		(owner, block_number),
+	);
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		put_v0_claim(b"document", 1, 5);
+		put_v0_claim(b"another document", 3, 7);
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(MigrateToV1::<Test>::pre_upgrade());
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(MigrateToV1::<Test>::post_upgrade());
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+		assert_eq!(Proofs::<Test>::iter().count(), 2);
+
+		let claim = claim_of(b"document");
+		let deposit = PoeModule::claim_deposit(&claim, 0);
+		let info = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!((info.owner, info.block_number, info.deposit), (1, 5, deposit));
+		assert!(info.description.is_empty() && info.mime_type.is_empty());
+		assert!(ClaimsByOwner::<Test>::contains_key(1, &claim));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+
+		// Account 3 cannot afford the deposit, so its claim is kept without one.
+		let claim = claim_of(b"another document");
+		let info = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!((info.owner, info.block_number, info.deposit), (3, 7, 0));
+		assert!(ClaimsByOwner::<Test>::contains_key(3, &claim));
+
+		// Migrated claims behave like any other.
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn migrate_to_v1_rekeys_every_v0_claim() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		// v0 claims were raw bytes of any length, including the length of a digest.
+		let claims: Vec<Vec<u8>> = vec![vec![], vec![7; 32], vec![9; 512]];
+		for data in &claims {
+			put_v0_claim(data, 1, 5);
+		}
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		for data in &claims {
+			assert_eq!(Proofs::<Test>::get(&claim_of(data)).unwrap().owner, 1);
+		}
+		// Every entry was moved to its new key, none is left under its raw bytes.
+		let prefix = Proofs::<Test>::final_prefix().to_vec();
+		let keys = KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(())).count();
+		assert_eq!(keys, claims.len());
+	});
+}
+
+#[test]
+fn migrate_to_v1_skips_migrated_storage() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"document"), vec![], vec![], None));
+		let before = Proofs::<Test>::get(&claim_of(b"document"));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")), before);
+	});
+}
+
+#[test]
+fn claims_by_owner_follows_create_transfer_and_revoke() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"other"), vec![], vec![], None));
+		assert_eq!(PoeModule::claims_of(&1).len(), 2);
+		assert!(ClaimsByOwner::<Test>::contains_key(1, claim_of(b"document")));
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_eq!(PoeModule::claims_of(&1), vec![claim_of(b"other")]);
+		assert_eq!(PoeModule::claims_of(&2), vec![claim_of(b"document")]);
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+		assert!(PoeModule::claims_of(&2).is_empty());
+		assert_eq!(PoeModule::claim_info(&claim_of(b"document")), None);
+	});
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_eq!(
+			ClaimOffers::<Test>::get(&claim_of(b"document")),
+			Some(ClaimOffer { from: 1, to: 2, expires_at: 11 })
+		);
+		// The claim stays with its owner until the offer is accepted.
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 1);
+
+		assert_ok!(PoeModule::accept_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 2);
+		assert_eq!(ClaimOffers::<Test>::get(&claim_of(b"document")), None);
+		assert!(OfferExpiries::<Test>::get(11).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimOfferAccepted(1, claim_of(b"document"), 2)));
+	});
+}
+
+#[test]
+fn offer_claim_failed_when_not_claim_owner_or_already_offered() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest, 3),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 1),
+			Error::<Test>::OfferToSelf
+		);
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 3),
+			Error::<Test>::OfferAlreadyExist
+		);
+	});
+}
+
+#[test]
+fn accept_claim_failed_when_not_offer_recipient() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::OfferNotExist
+		);
+
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotOfferRecipient
+		);
+	});
+}
+
+#[test]
+fn reject_and_cancel_offer_keep_claim_with_owner() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_ok!(PoeModule::reject_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(ClaimOffers::<Test>::get(&claim_of(b"document")), None);
+
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_noop!(
+			PoeModule::cancel_offer(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_offer(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(ClaimOffers::<Test>::get(&claim_of(b"document")), None);
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn offers_expire_on_initialize() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+
+		PoeModule::on_initialize(10);
+		assert!(ClaimOffers::<Test>::contains_key(&claim_of(b"document")));
+
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert_eq!(ClaimOffers::<Test>::get(&claim_of(b"document")), None);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimOfferExpired(1, claim_of(b"document"), 2)));
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::OfferNotExist
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_drops_pending_offer() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(ClaimOffers::<Test>::get(&claim_of(b"document")), None);
+		assert!(OfferExpiries::<Test>::get(11).is_empty());
+	});
+}
+
+#[test]
+fn create_claims_and_revoke_claims_work() {
+	new_test_ext().execute_with(|| {
+		let batch = vec![
+			(HashAlgorithm::Blake2_256, digest_of(b"a")),
+			(HashAlgorithm::Blake2_256, digest_of(b"b")),
+			(HashAlgorithm::Blake2_256, digest_of(b"c")),
+		];
+		assert_ok!(PoeModule::create_claims(Origin::signed(1), batch.clone()));
+		assert_eq!(PoeModule::claims_of(&1).len(), 3);
+		assert_eq!(Balances::reserved_balance(1), 3 * PoeModule::claim_deposit(&claim_of(b"a"), 0));
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimsCreated(
+			1,
+			vec![claim_of(b"a"), claim_of(b"b"), claim_of(b"c")],
+		)));
+
+		assert_ok!(PoeModule::revoke_claims(Origin::signed(1), batch));
+		assert!(PoeModule::claims_of(&1).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn revoke_claims_is_refunded_down_to_the_metadata_revoked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"a"), b"doc".to_vec(), vec![], None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"b"), vec![], vec![], None));
+		let batch = vec![(HashAlgorithm::Blake2_256, digest_of(b"a")), (HashAlgorithm::Blake2_256, digest_of(b"b"))];
+
+		let info = PoeModule::revoke_claims(Origin::signed(1), batch).unwrap();
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::revoke_claims(2, 3)));
+	});
+}
+
+#[test]
+fn create_claims_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest_of(b"b"), vec![], vec![], None));
+
+		assert_noop!(
+			PoeModule::create_claims(
+				Origin::signed(1),
+				vec![(HashAlgorithm::Blake2_256, digest_of(b"a")), (HashAlgorithm::Blake2_256, digest_of(b"b"))]
+			),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"a")));
+	});
+}
+
+#[test]
+fn revoke_claims_failed_when_any_claim_not_owned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"a"), vec![], vec![], None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest_of(b"b"), vec![], vec![], None));
+
+		assert_noop!(
+			PoeModule::revoke_claims(
+				Origin::signed(1),
+				vec![(HashAlgorithm::Blake2_256, digest_of(b"a")), (HashAlgorithm::Blake2_256, digest_of(b"b"))]
+			),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn batch_failed_when_too_large() {
+	new_test_ext().execute_with(|| {
+		let batch = (0u8..4).map(|i| (HashAlgorithm::Blake2_256, digest_of(&[i]))).collect::<Vec<_>>();
+		assert_noop!(PoeModule::create_claims(Origin::signed(1), batch.clone()), Error::<Test>::BatchTooLarge);
+		assert_noop!(PoeModule::revoke_claims(Origin::signed(1), batch), Error::<Test>::BatchTooLarge);
+	});
+}
+
+#[test]
+fn anchor_root_works() {
+	new_test_ext().execute_with(|| {
+		let leaves: Vec<_> = (0u8..5).map(|i| HashAlgorithm::Blake2_256.hash(&[i])).collect();
+		let root = merkle::root(&leaves).unwrap();
+
+		assert_ok!(PoeModule::anchor_root(Origin::signed(1), root, 5));
+		let info = Roots::<Test>::get(&root).unwrap();
+		assert_eq!((info.owner, info.leaf_count), (1, 5));
+		assert_eq!(Balances::reserved_balance(1), PoeModule::root_deposit());
+		System::assert_last_event(Event::PoeModule(PoeEvent::RootAnchored(1, root, 5)));
+
+		assert_noop!(PoeModule::anchor_root(Origin::signed(2), root, 5), Error::<Test>::RootAlreadyExist);
+		assert_noop!(
+			PoeModule::anchor_root(Origin::signed(1), HashAlgorithm::Blake2_256.hash(b"x"), 0),
+			Error::<Test>::EmptyTree
+		);
+	});
+}
+
+#[test]
+fn verify_inclusion_works() {
+	new_test_ext().execute_with(|| {
+		let leaves: Vec<_> = (0u8..5).map(|i| HashAlgorithm::Blake2_256.hash(&[i])).collect();
+		let root = merkle::root(&leaves).unwrap();
+		let proof = merkle::proof(&leaves, 4).unwrap();
+
+		// Nothing verifies against a root that was never anchored.
+		assert!(!PoeModule::verify_inclusion(&root, &leaves[4], &proof));
+
+		assert_ok!(PoeModule::anchor_root(Origin::signed(1), root, 5));
+		for (index, leaf) in leaves.iter().enumerate() {
+			assert!(PoeModule::verify_inclusion(&root, leaf, &merkle::proof(&leaves, index).unwrap()));
+		}
+		assert!(!PoeModule::verify_inclusion(&root, &HashAlgorithm::Blake2_256.hash(b"x"), &proof));
+		assert!(!PoeModule::verify_inclusion(&root, &leaves[3], &proof));
+	});
+}
+
+#[test]
+fn verify_inclusion_rejects_inner_nodes_and_long_proofs() {
+	new_test_ext().execute_with(|| {
+		let leaves: Vec<_> = (0u8..4).map(|i| HashAlgorithm::Blake2_256.hash(&[i])).collect();
+		let root = merkle::root(&leaves).unwrap();
+		assert_ok!(PoeModule::anchor_root(Origin::signed(1), root, 4));
+
+		// The parent of the first two leaves is not itself a leaf.
+		let left = merkle::node_hash(&merkle::leaf_hash(&leaves[0]), &merkle::leaf_hash(&leaves[1]));
+		let right = merkle::node_hash(&merkle::leaf_hash(&leaves[2]), &merkle::leaf_hash(&leaves[3]));
+		assert!(!PoeModule::verify_inclusion(&root, &left, &[right]));
+
+		let mut proof = merkle::proof(&leaves, 0).unwrap();
+		proof.push(root);
+		assert!(!PoeModule::verify_inclusion(&root, &leaves[0], &proof));
+	});
+}
+
+#[test]
+fn revoke_root_works() {
+	new_test_ext().execute_with(|| {
+		let root = HashAlgorithm::Blake2_256.hash(b"root");
+		assert_ok!(PoeModule::anchor_root(Origin::signed(1), root, 1));
+
+		assert_noop!(PoeModule::revoke_root(Origin::signed(2), root), Error::<Test>::NotClaimOwner);
+		assert_ok!(PoeModule::revoke_root(Origin::signed(1), root));
+		assert!(!Roots::<Test>::contains_key(&root));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::RootRevoked(1, root)));
+
+		assert_noop!(PoeModule::revoke_root(Origin::signed(1), root), Error::<Test>::RootNotExist);
+	});
+}
+
+#[test]
+fn claims_expire_on_initialize() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(5)));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_eq!(ClaimExpiries::<Test>::get(&claim_of(b"document")), Some(5));
+
+		PoeModule::on_initialize(4);
+		assert!(Proofs::<Test>::contains_key(&claim_of(b"document")));
+
+		System::set_block_number(5);
+		PoeModule::on_initialize(5);
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert!(!ClaimExpiries::<Test>::contains_key(&claim_of(b"document")));
+		assert!(!ClaimOffers::<Test>::contains_key(&claim_of(b"document")));
+		assert!(PoeModule::claims_of(&1).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimExpired(1, claim_of(b"document"))));
+	});
+}
+
+#[test]
+fn create_claim_failed_with_bad_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"a"), vec![], vec![], Some(1)),
+			Error::<Test>::ExpiryInPast
+		);
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"a"), vec![], vec![], Some(5)));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"b"), vec![], vec![], Some(5)));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"c"), vec![], vec![], Some(5)),
+			Error::<Test>::TooManyExpiries
+		);
+	});
+}
+
+#[test]
+fn renew_claim_works() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(5)));
+
+		assert_noop!(
+			PoeModule::renew_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest, Some(20)),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::renew_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, Some(20)));
+		assert_eq!(ClaimExpiries::<Test>::get(&claim_of(b"document")), Some(20));
+		assert!(ExpiryQueue::<Test>::get(5).is_empty());
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimRenewed(1, claim_of(b"document"), Some(20))));
+
+		System::set_block_number(5);
+		PoeModule::on_initialize(5);
+		assert!(Proofs::<Test>::contains_key(&claim_of(b"document")));
+
+		assert_ok!(PoeModule::renew_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, None));
+		assert!(!ClaimExpiries::<Test>::contains_key(&claim_of(b"document")));
+		assert!(ExpiryQueue::<Test>::get(20).is_empty());
+	});
+}
+
+#[test]
+fn revoke_claim_drops_expiry() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(5)));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+
+		assert!(!ClaimExpiries::<Test>::contains_key(&claim_of(b"document")));
+		assert!(ExpiryQueue::<Test>::get(5).is_empty());
+	});
+}
+
+#[test]
+fn operator_can_revoke_claim() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 3));
+		System::assert_last_event(Event::PoeModule(PoeEvent::OperatorApproved(1, 3)));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest));
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimRevoked(1, claim_of(b"document"), 3)));
+	});
+}
+
+#[test]
+fn operator_can_revoke_claims_in_a_batch() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest_of(b"a"), b"doc".to_vec(), vec![], None));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest_of(b"b"), vec![], vec![], None));
+		let batch = vec![(HashAlgorithm::Blake2_256, digest_of(b"a")), (HashAlgorithm::Blake2_256, digest_of(b"b"))];
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 3));
+		assert_noop!(PoeModule::revoke_claims(Origin::signed(3), batch.clone()), Error::<Test>::NotClaimOwner);
+
+		assert_ok!(PoeModule::approve_operator(Origin::signed(2), 3));
+		let info = PoeModule::revoke_claims(Origin::signed(3), batch).unwrap();
+		assert!(PoeModule::claims_of(&1).is_empty() && PoeModule::claims_of(&2).is_empty());
+		assert_eq!((Balances::reserved_balance(1), Balances::reserved_balance(2)), (0, 0));
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimsRevoked(3, vec![claim_of(b"a"), claim_of(b"b")])));
+		// Refunded down to the longest metadata actually revoked.
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::revoke_claims(2, 3)));
+	});
+}
+
+#[test]
+fn operator_can_transfer_claim() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 3));
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest, 2));
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 2);
+		assert_eq!(
+			Balances::reserved_balance(2),
+			PoeModule::claim_deposit(&claim_of(b"document"), 0) +
+				PoeModule::history_deposit(&claim_of(b"document"))
+		);
+		System::assert_last_event(Event::PoeModule(PoeEvent::TransferClaim(1, claim_of(b"document"), 2, 3)));
+
+		// The approval was given by the previous owner, not by the new one.
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest, 1),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn revoke_operator_works() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 3));
+
+		assert_ok!(PoeModule::revoke_operator(Origin::signed(1), 3));
+		assert!(!Operators::<Test>::contains_key(1, 3));
+		System::assert_last_event(Event::PoeModule(PoeEvent::OperatorRevoked(1, 3)));
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(PoeModule::revoke_operator(Origin::signed(1), 3), Error::<Test>::NotOperator);
+	});
+}
+
+#[test]
+fn multi_claim_is_finalized_at_threshold() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![1, 2, 3], 2));
+		let deposit = Balances::reserved_balance(1);
+		assert_eq!(deposit, PoeModule::claim_deposit(&claim_of(b"document"), 1 + 3 * 8));
+		System::assert_last_event(Event::PoeModule(PoeEvent::MultiClaimCreated(1, claim_of(b"document"), 2, 11)));
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest, vec![], vec![], None),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		assert_ok!(PoeModule::attest_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest));
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_noop!(
+			PoeModule::attest_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::AlreadyAttested
+		);
+
+		assert_ok!(PoeModule::attest_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+		let info = Proofs::<Test>::get(&claim_of(b"document")).unwrap();
+		assert_eq!((info.owner, info.deposit), (1, deposit));
+		assert_eq!(PoeModule::attestors_of(&claim_of(b"document")), vec![3, 2]);
+		assert!(!PendingClaims::<Test>::contains_key(&claim_of(b"document")));
+		assert!(PendingDeadlines::<Test>::get(11).is_empty());
+		System::assert_last_event(Event::PoeModule(PoeEvent::MultiClaimFinalized(1, claim_of(b"document"))));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+		assert!(!Attestors::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn create_multi_claim_failed_with_bad_signers() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_noop!(
+			PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2, 3], 0),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2, 3], 3),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2, 3, 4, 5], 2),
+			Error::<Test>::TooManySigners
+		);
+		assert_noop!(
+			PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2, 3, 2], 2),
+			Error::<Test>::DuplicateSigner
+		);
+	});
+}
+
+#[test]
+fn attest_claim_failed_when_not_signer() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_noop!(
+			PoeModule::attest_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::PendingClaimNotExist
+		);
+
+		assert_ok!(PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2], 1));
+		assert_noop!(
+			PoeModule::attest_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotSigner
+		);
+	});
+}
+
+#[test]
+fn pending_multi_claims_are_discarded_at_deadline() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_multi_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![2, 3], 2));
+		assert_ok!(PoeModule::attest_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert!(!PendingClaims::<Test>::contains_key(&claim_of(b"document")));
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::MultiClaimDiscarded(1, claim_of(b"document"))));
+		assert_noop!(
+			PoeModule::attest_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::PendingClaimNotExist
+		);
 	});
 }
+
+#[test]
+fn create_claim_for_works() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		assert_ok!(PoeModule::create_claim_for(
+			Origin::signed(1),
+			HashAlgorithm::Blake2_256,
+			digest_of(b"document"),
+			4,
+			TestSignature(4, payload),
+			0,
+			5
+		));
+
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0);
+		let info = Proofs::<Test>::get(&claim_of(b"document")).unwrap();
+		assert_eq!((info.owner, info.deposit), (4, deposit));
+		assert_eq!(PoeModule::claims_of(&4), vec![claim_of(b"document")]);
+		assert_eq!(Nonces::<Test>::get(4), 1);
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(ClaimDepositors::<Test>::get(&claim_of(b"document")), Some(1));
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimCreatedFor(4, claim_of(b"document"), 1)));
+
+		// Revoking the claim releases the deposit to the relayer.
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(4), HashAlgorithm::Blake2_256, digest_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!ClaimDepositors::<Test>::contains_key(&claim_of(b"document")));
+	});
+}
+
+#[test]
+fn create_claim_for_deposit_moves_to_owner_on_transfer() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		assert_ok!(PoeModule::create_claim_for(
+			Origin::signed(1),
+			HashAlgorithm::Blake2_256,
+			digest_of(b"document"),
+			4,
+			TestSignature(4, payload),
+			0,
+			5
+		));
+
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(4), HashAlgorithm::Blake2_256, digest_of(b"document"), 2));
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0) +
+			PoeModule::history_deposit(&claim_of(b"document"));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+		assert!(!ClaimDepositors::<Test>::contains_key(&claim_of(b"document")));
+	});
+}
+
+#[test]
+fn create_claim_for_failed_without_relayer_deposit() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		// Account 5 holds nothing.
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(5),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(4, payload),
+				0,
+				5
+			),
+			Error::<Test>::NotEnoughBalanceForDeposit
+		);
+	});
+}
+
+#[test]
+fn create_claim_for_failed_with_bad_signature() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		// Signed by someone else.
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(5, payload.clone()),
+				0,
+				5
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// Signed for another claim.
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"other"),
+				4,
+				TestSignature(4, payload.clone()),
+				0,
+				5
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// Signed with another deadline.
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(4, payload),
+				0,
+				6
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// Signed for another chain.
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(4, payload),
+				0,
+				5
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn create_claim_for_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		assert_ok!(PoeModule::create_claim_for(
+			Origin::signed(1),
+			HashAlgorithm::Blake2_256,
+			digest_of(b"document"),
+			4,
+			TestSignature(4, payload.clone()),
+			0,
+			5
+		));
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(4), HashAlgorithm::Blake2_256, digest_of(b"document")));
+
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(4, payload),
+				0,
+				5
+			),
+			Error::<Test>::InvalidNonce
+		);
+	});
+}
+
+#[test]
+fn create_claim_for_failed_after_deadline() {
+	new_test_ext().execute_with(|| {
+		let payload = PoeModule::claim_payload(&claim_of(b"document"), &4, 0, 5);
+		System::set_block_number(6);
+		assert_noop!(
+			PoeModule::create_claim_for(
+				Origin::signed(1),
+				HashAlgorithm::Blake2_256,
+				digest_of(b"document"),
+				4,
+				TestSignature(4, payload),
+				0,
+				5
+			),
+			Error::<Test>::SignatureExpired
+		);
+	});
+}
+
+#[test]
+fn transfers_are_recorded_in_claim_history() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert!(PoeModule::claim_history(&claim_of(b"document")).is_empty());
+
+		System::set_block_number(3);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		System::set_block_number(7);
+		assert_ok!(PoeModule::offer_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest, 1));
+		assert_ok!(PoeModule::accept_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest));
+
+		assert_eq!(
+			PoeModule::claim_history(&claim_of(b"document")),
+			vec![
+				OwnershipRecord { owner: 1, from: 1, to: 3 },
+				OwnershipRecord { owner: 2, from: 3, to: 7 },
+			]
+		);
+	});
+}
+
+#[test]
+fn claim_history_drops_oldest_record_when_full() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		for (block, owner, dest) in [(2, 1, 2), (3, 2, 1), (4, 1, 2)] {
+			System::set_block_number(block);
+			assert_ok!(PoeModule::transfer_claim(Origin::signed(owner), HashAlgorithm::Blake2_256, digest, dest));
+		}
+
+		assert_eq!(
+			PoeModule::claim_history(&claim_of(b"document")),
+			vec![
+				OwnershipRecord { owner: 2, from: 2, to: 3 },
+				OwnershipRecord { owner: 1, from: 3, to: 4 },
+			]
+		);
+	});
+}
+
+#[test]
+fn claim_history_outlives_the_claim() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		System::set_block_number(3);
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		System::set_block_number(5);
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(
+			PoeModule::claim_history(&claim_of(b"document")),
+			vec![
+				OwnershipRecord { owner: 1, from: 1, to: 3 },
+				OwnershipRecord { owner: 2, from: 3, to: 5 },
+			]
+		);
+
+		// Whoever creates the claim again pays for the history it carries on.
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0) +
+			PoeModule::history_deposit(&claim_of(b"document"));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().deposit, deposit);
+	});
+}
+
+#[test]
+fn removing_a_claim_charges_for_its_history() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		let deposit = Proofs::<Test>::get(&claim_of(b"document")).unwrap().deposit;
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest));
+		// The records of both owners stay, so their share of the deposit is not given back.
+		let history_fee = PoeModule::history_deposit(&claim_of(b"document"));
+		assert_eq!(PoeModule::claim_history(&claim_of(b"document")).len(), 2);
+		assert!(history_fee > 0 && history_fee < deposit);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000_000_000 - history_fee);
+	});
+}
+
+#[test]
+fn claim_history_is_kept_when_a_claim_expires() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(4)));
+
+		System::set_block_number(4);
+		PoeModule::on_initialize(4);
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(
+			PoeModule::claim_history(&claim_of(b"document")),
+			vec![OwnershipRecord { owner: 1, from: 1, to: 4 }]
+		);
+	});
+}
+
+#[test]
+fn frozen_claim_cannot_be_revoked_or_transferred() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::approve_operator(Origin::signed(1), 3));
+
+		assert_noop!(
+			PoeModule::freeze_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimFrozen(claim_of(b"document"))));
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(3), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			PoeModule::revoke_claims(Origin::signed(1), vec![(HashAlgorithm::Blake2_256, digest)]),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2),
+			Error::<Test>::Frozen
+		);
+
+		assert_ok!(PoeModule::unfreeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimUnfrozen(claim_of(b"document"))));
+		assert_ok!(PoeModule::transfer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_noop!(
+			PoeModule::unfreeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotFrozen
+		);
+	});
+}
+
+#[test]
+fn offered_claim_cannot_be_accepted_once_frozen() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::offer_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, 2));
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+
+		assert_noop!(
+			PoeModule::accept_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::Frozen
+		);
+	});
+}
+
+#[test]
+fn frozen_claim_does_not_expire() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(5)));
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+
+		System::set_block_number(5);
+		PoeModule::on_initialize(5);
+		assert!(Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_noop!(
+			PoeModule::renew_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, Some(10)),
+			Error::<Test>::Frozen
+		);
+
+		// Once unfrozen, a claim whose expiry has passed expires in the next block.
+		assert_ok!(PoeModule::unfreeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(ClaimExpiries::<Test>::get(&claim_of(b"document")), Some(6));
+		System::set_block_number(6);
+		PoeModule::on_initialize(6);
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn unfrozen_claim_keeps_its_future_expiry() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], Some(5)));
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+		assert_ok!(PoeModule::unfreeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+
+		assert_eq!(ClaimExpiries::<Test>::get(&claim_of(b"document")), Some(5));
+		System::set_block_number(5);
+		PoeModule::on_initialize(5);
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+	});
+}
+
+#[test]
+fn force_revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		assert_ok!(PoeModule::freeze_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::signed(1), HashAlgorithm::Blake2_256, digest),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), HashAlgorithm::Blake2_256, digest));
+		assert!(!Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert!(!FrozenClaims::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::ClaimForceRevoked(1, claim_of(b"document"))));
+
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::root(), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::ClaimNotExist
+		);
+	});
+}
+
+fn namespace(name: &[u8]) -> NamespaceOf<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn register_namespace_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::register_namespace(Origin::signed(1), b"acme".to_vec()));
+		let info = Namespaces::<Test>::get(&namespace(b"acme")).unwrap();
+		assert_eq!(info.owner, 1);
+		assert_eq!(Balances::reserved_balance(1), info.deposit);
+		System::assert_last_event(Event::PoeModule(PoeEvent::NamespaceRegistered(1, b"acme".to_vec())));
+
+		assert_noop!(
+			PoeModule::register_namespace(Origin::signed(2), b"acme".to_vec()),
+			Error::<Test>::NamespaceAlreadyExist
+		);
+		assert_noop!(
+			PoeModule::register_namespace(Origin::signed(2), vec![]),
+			Error::<Test>::InvalidNamespace
+		);
+		assert_noop!(
+			PoeModule::register_namespace(Origin::signed(2), b"too long!".to_vec()),
+			Error::<Test>::InvalidNamespace
+		);
+		assert_noop!(
+			PoeModule::register_namespace(Origin::signed(3), b"poor".to_vec()),
+			Error::<Test>::NotEnoughBalanceForDeposit
+		);
+	});
+}
+
+#[test]
+fn namespaced_claims_do_not_collide() {
+	new_test_ext().execute_with(|| {
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::register_namespace(Origin::signed(1), b"acme".to_vec()));
+		assert_ok!(PoeModule::register_namespace(Origin::signed(2), b"globex".to_vec()));
+
+		assert_ok!(PoeModule::create_namespaced_claim(Origin::signed(1), b"acme".to_vec(), HashAlgorithm::Blake2_256, digest, vec![], vec![]));
+		assert_ok!(PoeModule::create_namespaced_claim(Origin::signed(2), b"globex".to_vec(), HashAlgorithm::Blake2_256, digest, vec![], vec![]));
+		assert_ok!(PoeModule::create_claim(Origin::signed(2), HashAlgorithm::Blake2_256, digest, vec![], vec![], None));
+		System::assert_has_event(Event::PoeModule(PoeEvent::NamespacedClaimCreated(b"acme".to_vec(), 1, claim_of(b"document"))));
+
+		assert_eq!(PoeModule::namespaced_claim_info(b"acme".to_vec(), &claim_of(b"document")).unwrap().owner, 1);
+		assert_eq!(PoeModule::namespaced_claim_info(b"globex".to_vec(), &claim_of(b"document")).unwrap().owner, 2);
+		assert_eq!(Proofs::<Test>::get(&claim_of(b"document")).unwrap().owner, 2);
+		assert_noop!(
+			PoeModule::create_namespaced_claim(Origin::signed(1), b"acme".to_vec(), HashAlgorithm::Blake2_256, digest, vec![], vec![]),
+			Error::<Test>::ProofAlreadyExist
+		);
+		assert_noop!(
+			PoeModule::create_namespaced_claim(Origin::signed(1), b"initech".to_vec(), HashAlgorithm::Blake2_256, digest, vec![], vec![]),
+			Error::<Test>::NamespaceNotExist
+		);
+
+		// Revoking from one namespace leaves the others alone.
+		assert_ok!(PoeModule::revoke_namespaced_claim(Origin::signed(1), b"acme".to_vec(), HashAlgorithm::Blake2_256, digest));
+		assert!(PoeModule::namespaced_claim_info(b"acme".to_vec(), &claim_of(b"document")).is_none());
+		assert!(PoeModule::namespaced_claim_info(b"globex".to_vec(), &claim_of(b"document")).is_some());
+		assert!(Proofs::<Test>::contains_key(&claim_of(b"document")));
+		assert_eq!(Balances::reserved_balance(1), Namespaces::<Test>::get(&namespace(b"acme")).unwrap().deposit);
+	});
+}
+
+#[test]
+fn namespace_admins_work() {
+	new_test_ext().execute_with(|| {
+		let acme = b"acme".to_vec();
+		let digest = digest_of(b"document");
+		assert_ok!(PoeModule::register_namespace(Origin::signed(1), acme.clone()));
+		assert_noop!(
+			PoeModule::create_namespaced_claim(Origin::signed(2), acme.clone(), HashAlgorithm::Blake2_256, digest, vec![], vec![]),
+			Error::<Test>::NotNamespaceAdmin
+		);
+		assert_noop!(
+			PoeModule::add_namespace_admin(Origin::signed(2), acme.clone(), 2),
+			Error::<Test>::NotNamespaceAdmin
+		);
+
+		assert_ok!(PoeModule::add_namespace_admin(Origin::signed(1), acme.clone(), 2));
+		assert!(NamespaceAdmins::<Test>::contains_key(&namespace(b"acme"), 2));
+		System::assert_last_event(Event::PoeModule(PoeEvent::NamespaceAdminAdded(acme.clone(), 2)));
+		assert_ok!(PoeModule::create_namespaced_claim(Origin::signed(2), acme.clone(), HashAlgorithm::Blake2_256, digest, vec![], vec![]));
+		let deposit = PoeModule::claim_deposit(&claim_of(b"document"), 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+
+		// The namespace owner may revoke a claim created by an admin.
+		assert_ok!(PoeModule::revoke_namespaced_claim(Origin::signed(1), acme.clone(), HashAlgorithm::Blake2_256, digest));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(Event::PoeModule(PoeEvent::NamespacedClaimRevoked(acme.clone(), 2, claim_of(b"document"), 1)));
+
+		// A removed admin can no longer create claims, but can still revoke its own.
+		assert_ok!(PoeModule::create_namespaced_claim(Origin::signed(2), acme.clone(), HashAlgorithm::Blake2_256, digest, vec![], vec![]));
+		assert_ok!(PoeModule::remove_namespace_admin(Origin::signed(1), acme.clone(), 2));
+		assert_noop!(
+			PoeModule::remove_namespace_admin(Origin::signed(1), acme.clone(), 2),
+			Error::<Test>::NotNamespaceAdmin
+		);
+		assert_noop!(
+			PoeModule::create_namespaced_claim(Origin::signed(2), acme.clone(), HashAlgorithm::Blake2_256, digest_of(b"other"), vec![], vec![]),
+			Error::<Test>::NotNamespaceAdmin
+		);
+		assert_noop!(
+			PoeModule::revoke_namespaced_claim(Origin::signed(3), acme.clone(), HashAlgorithm::Blake2_256, digest),
+			Error::<Test>::NotNamespaceAdmin
+		);
+		assert_ok!(PoeModule::revoke_namespaced_claim(Origin::signed(2), acme.clone(), HashAlgorithm::Blake2_256, digest));
+		assert!(NamespacedProofs::<Test>::iter_prefix(&namespace(b"acme")).next().is_none());
+	});
+}
+
+#[test]
+fn call_indices_follow_declaration_order() {
+	// A call is indexed by its position in the call impl, and signed transactions and client
+	// metadata depend on it, so new calls must only ever be appended.
+	let (algorithm, digest) = (HashAlgorithm::Blake2_256, digest_of(b"document"));
+	let index = |call: crate::Call<Test>| call.encode()[0];
+
+	assert_eq!(index(crate::Call::transfer_claim { algorithm, digest, dest: 2 }), 2);
+	assert_eq!(
+		index(crate::Call::update_claim_metadata { algorithm, digest, description: vec![], mime_type: vec![] }),
+		3
+	);
+	assert_eq!(index(crate::Call::reject_claim { algorithm, digest }), 6);
+	assert_eq!(index(crate::Call::cancel_offer { algorithm, digest }), 7);
+	assert_eq!(index(crate::Call::create_claims { claims: vec![] }), 8);
+	assert_eq!(index(crate::Call::revoke_namespaced_claim { name: vec![], algorithm, digest }), 25);
+}
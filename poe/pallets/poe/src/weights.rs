@@ -15,35 +15,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_poe
+//! Placeholder weights for pallet_poe.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-10-08, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `shidonghuas-MacBook-Pro.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/node-poe
-// benchmark
-// pallet
-// --chain
-// dev
-// --execution
-// wasm
-// --wasm-execution
-// compiled
-// --pallet
-// pallet_poe
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// ./pallets/poe/src/weights.rs
-// --template
-// .maintain/frame-weight-template.hbs
+//! These figures are estimates, NOT benchmark results: no benchmark run has produced them yet.
+//! Replace this file with the output of the pallet benchmarks on reference hardware:
+//!
+//! ./target/release/node-poe benchmark pallet --chain dev --execution wasm --wasm-execution compiled \
+//!     --pallet pallet_poe --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output ./pallets/poe/src/weights.rs --template .maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -54,70 +33,648 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_poe.
 pub trait WeightInfo {
-	fn create_claim(d: u32, ) -> Weight;
-	fn revoke_claim(d: u32, ) -> Weight;
-	fn transfer_claim(d: u32, ) -> Weight;
+	fn create_claim() -> Weight;
+	fn revoke_claim() -> Weight;
+	fn transfer_claim() -> Weight;
+	fn update_claim_metadata() -> Weight;
+	fn offer_claim() -> Weight;
+	fn accept_claim() -> Weight;
+	fn reject_claim() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn expire_offers(o: u32, ) -> Weight;
+	fn create_claims(b: u32, ) -> Weight;
+	fn revoke_claims(b: u32, l: u32, ) -> Weight;
+	fn anchor_root() -> Weight;
+	fn revoke_root() -> Weight;
+	fn renew_claim() -> Weight;
+	fn expire_claims(c: u32, ) -> Weight;
+	fn approve_operator() -> Weight;
+	fn revoke_operator() -> Weight;
+	fn create_multi_claim(s: u32, ) -> Weight;
+	fn attest_claim() -> Weight;
+	fn discard_multi_claims(p: u32, ) -> Weight;
+	fn create_claim_for() -> Weight;
+	fn freeze_claim() -> Weight;
+	fn unfreeze_claim() -> Weight;
+	fn force_revoke_claim() -> Weight;
+	fn register_namespace() -> Weight;
+	fn add_namespace_admin() -> Weight;
+	fn remove_namespace_admin() -> Weight;
+	fn create_namespaced_claim() -> Weight;
+	fn revoke_namespaced_claim() -> Weight;
 }
 
 /// Weights for pallet_poe using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn create_claim(d: u32, ) -> Weight {
-		(16_029_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((5_000 as Weight).saturating_mul(d as Weight))
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn create_claim() -> Weight {
+		(41_774_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn revoke_claim() -> Weight {
+		(43_127_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn transfer_claim() -> Weight {
+		(55_389_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn update_claim_metadata() -> Weight {
+		(33_652_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	fn offer_claim() -> Weight {
+		(27_935_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn accept_claim() -> Weight {
+		(58_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	fn reject_claim() -> Weight {
+		(21_447_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	fn cancel_offer() -> Weight {
+		(21_302_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	/// The range of component `o` is `[0, 64]`.
+	fn expire_offers(o: u32, ) -> Weight {
+		(2_913_000 as Weight)
+			.saturating_add((7_846_000 as Weight).saturating_mul(o as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	/// The range of component `b` is `[0, 1000]`.
+	fn create_claims(b: u32, ) -> Weight {
+		(4_126_000 as Weight)
+			.saturating_add((31_870_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:0)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	/// The range of component `b` is `[0, 1000]`.
+	/// The range of component `l` is `[0, 512]`.
+	fn revoke_claims(b: u32, l: u32, ) -> Weight {
+		(3_894_000 as Weight)
+			.saturating_add((28_466_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: PoeModule Roots (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn anchor_root() -> Weight {
+		(33_418_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Roots (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_root() -> Weight {
+		(30_952_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	fn renew_claim() -> Weight {
+		(37_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:0 w:1)
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn revoke_claim(d: u32, ) -> Weight {
-		(16_880_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((5_000 as Weight).saturating_mul(d as Weight))
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn expire_claims(c: u32, ) -> Weight {
+		(2_874_000 as Weight)
+			.saturating_add((27_513_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: PoeModule Operators (r:0 w:1)
+	fn approve_operator() -> Weight {
+		(17_248_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Operators (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		(19_503_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	/// The range of component `s` is `[1, 16]`.
+	fn create_multi_claim(s: u32, ) -> Weight {
+		(38_562_000 as Weight)
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule Proofs (r:0 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	fn attest_claim() -> Weight {
+		(44_931_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 64]`.
+	fn discard_multi_claims(p: u32, ) -> Weight {
+		(2_791_000 as Weight)
+			.saturating_add((19_634_000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
 	}
+	// Storage: PoeModule Nonces (r:1 w:1)
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn transfer_claim(d: u32, ) -> Weight {
-		(18_071_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((6_000 as Weight).saturating_mul(d as Weight))
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimDepositors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn create_claim_for() -> Weight {
+		(98_617_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	fn freeze_claim() -> Weight {
+		(21_064_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	fn unfreeze_claim() -> Weight {
+		(27_412_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn force_revoke_claim() -> Weight {
+		(41_508_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_namespace() -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:0 w:1)
+	fn add_namespace_admin() -> Weight {
+		(18_377_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:1)
+	fn remove_namespace_admin() -> Weight {
+		(19_902_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:0)
+	// Storage: PoeModule NamespacedProofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_namespaced_claim() -> Weight {
+		(38_611_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule NamespacedProofs (r:1 w:1)
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_namespaced_claim() -> Weight {
+		(34_087_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn create_claim(d: u32, ) -> Weight {
-		(16_029_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((5_000 as Weight).saturating_mul(d as Weight))
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn create_claim() -> Weight {
+		(41_774_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn revoke_claim() -> Weight {
+		(43_127_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn transfer_claim() -> Weight {
+		(55_389_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn update_claim_metadata() -> Weight {
+		(33_652_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	fn offer_claim() -> Weight {
+		(27_935_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn accept_claim() -> Weight {
+		(58_106_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	fn reject_claim() -> Weight {
+		(21_447_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	fn cancel_offer() -> Weight {
+		(21_302_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule OfferExpiries (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:1)
+	/// The range of component `o` is `[0, 64]`.
+	fn expire_offers(o: u32, ) -> Weight {
+		(2_913_000 as Weight)
+			.saturating_add((7_846_000 as Weight).saturating_mul(o as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	/// The range of component `b` is `[0, 1000]`.
+	fn create_claims(b: u32, ) -> Weight {
+		(4_126_000 as Weight)
+			.saturating_add((31_870_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:0)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Operators (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	/// The range of component `b` is `[0, 1000]`.
+	/// The range of component `l` is `[0, 512]`.
+	fn revoke_claims(b: u32, l: u32, ) -> Weight {
+		(3_894_000 as Weight)
+			.saturating_add((28_466_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: PoeModule Roots (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn anchor_root() -> Weight {
+		(33_418_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Roots (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_root() -> Weight {
+		(30_952_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:2 w:2)
+	fn renew_claim() -> Weight {
+		(37_210_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:0 w:1)
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn revoke_claim(d: u32, ) -> Weight {
-		(16_880_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((5_000 as Weight).saturating_mul(d as Weight))
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn expire_claims(c: u32, ) -> Weight {
+		(2_874_000 as Weight)
+			.saturating_add((27_513_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(c as Weight)))
 	}
+	// Storage: PoeModule Operators (r:0 w:1)
+	fn approve_operator() -> Weight {
+		(17_248_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Operators (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		(19_503_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	/// The range of component `s` is `[1, 16]`.
+	fn create_multi_claim(s: u32, ) -> Weight {
+		(38_562_000 as Weight)
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule Proofs (r:0 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	fn attest_claim() -> Weight {
+		(44_931_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule PendingDeadlines (r:1 w:1)
+	// Storage: PoeModule PendingClaims (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `p` is `[0, 64]`.
+	fn discard_multi_claims(p: u32, ) -> Weight {
+		(2_791_000 as Weight)
+			.saturating_add((19_634_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: PoeModule Nonces (r:1 w:1)
 	// Storage: PoeModule Proofs (r:1 w:1)
-	/// The range of component `d` is `[0, 512]`.
-	fn transfer_claim(d: u32, ) -> Weight {
-		(18_071_000 as Weight)
-			// Standard Error: 0
-			.saturating_add((6_000 as Weight).saturating_mul(d as Weight))
+	// Storage: PoeModule PendingClaims (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule ClaimDepositors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:0)
+	fn create_claim_for() -> Weight {
+		(98_617_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	fn freeze_claim() -> Weight {
+		(21_064_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	fn unfreeze_claim() -> Weight {
+		(27_412_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule ClaimExpiries (r:1 w:1)
+	// Storage: PoeModule ExpiryQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimOffers (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	// Storage: PoeModule Attestors (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule ClaimDepositors (r:1 w:1)
+	fn force_revoke_claim() -> Weight {
+		(41_508_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register_namespace() -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:0 w:1)
+	fn add_namespace_admin() -> Weight {
+		(18_377_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:1)
+	fn remove_namespace_admin() -> Weight {
+		(19_902_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:0)
+	// Storage: PoeModule NamespacedProofs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn create_namespaced_claim() -> Weight {
+		(38_611_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule NamespacedProofs (r:1 w:1)
+	// Storage: PoeModule Namespaces (r:1 w:0)
+	// Storage: PoeModule NamespaceAdmins (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_namespaced_claim() -> Weight {
+		(34_087_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
\ No newline at end of file
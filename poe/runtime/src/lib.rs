@@ -262,10 +262,56 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	pub const ClaimDeposit: Balance = 1_000;
+	pub const ClaimByteDeposit: Balance = 10;
+	pub const OfferDuration: BlockNumber = DAYS;
+	pub const MultiClaimDuration: BlockNumber = 7 * DAYS;
+}
+
+/// Signs `create_claim_for` payloads with an sr25519 key from the benchmarking keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkHelper<sp_runtime::MultiSigner, Signature> for PoeBenchmarkHelper {
+	fn signer() -> sp_runtime::MultiSigner {
+		sp_io::crypto::sr25519_generate(KeyTypeId(*b"poe!"), None).into()
+	}
+
+	fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> Signature {
+		match signer {
+			sp_runtime::MultiSigner::Sr25519(public) =>
+				sp_io::crypto::sr25519_sign(KeyTypeId(*b"poe!"), public, message)
+					.expect("key was generated by `signer`; qed")
+					.into(),
+			_ => unreachable!("`signer` only hands out sr25519 keys; qed"),
+		}
+	}
+}
+
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type Event = Event;
-	type MaxClaimLength = ConstU32<512>;
+	type Currency = Balances;
+	type ClaimDeposit = ClaimDeposit;
+	type ClaimByteDeposit = ClaimByteDeposit;
+	type Timestamp = Timestamp;
+	type MaxMetadataLength = ConstU32<256>;
+	type OfferDuration = OfferDuration;
+	type MaxOffersPerBlock = ConstU32<64>;
+	type MaxBatchSize = ConstU32<1_000>;
+	type MaxExpiringPerBlock = ConstU32<64>;
+	type MaxSigners = ConstU32<16>;
+	type MultiClaimDuration = MultiClaimDuration;
+	type MaxPendingPerBlock = ConstU32<64>;
+	type MaxHistoryLength = ConstU32<32>;
+	type MaxNamespaceLength = ConstU32<64>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PoeBenchmarkHelper;
 	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 
 }
@@ -311,6 +357,8 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (pallet_poe::migrations::v1::MigrateToV1<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -318,6 +366,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -466,6 +515,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, pallet_poe::ClaimInfo<Runtime>> for Runtime {
+		fn claims_of(account: AccountId) -> Vec<pallet_poe::Claim> {
+			PoeModule::claims_of(&account)
+		}
+
+		fn claim_info(claim: pallet_poe::Claim) -> Option<pallet_poe::ClaimInfo<Runtime>> {
+			PoeModule::claim_info(&claim)
+		}
+
+		fn claim_history(claim: pallet_poe::Claim) -> Vec<pallet_poe::OwnershipRecord<AccountId, BlockNumber>> {
+			PoeModule::claim_history(&claim)
+		}
+
+		fn verify_inclusion(root: Hash, digest: Hash, proof: Vec<Hash>) -> bool {
+			PoeModule::verify_inclusion(&root, &digest, &proof)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (